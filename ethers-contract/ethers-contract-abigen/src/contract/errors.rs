@@ -70,6 +70,7 @@ impl Context {
         // use the same derives as for events
         let derives = util::expand_derives(&self.event_derives);
 
+        let error_ident = &error_name;
         let error_name = &error.name;
 
         Ok(quote! {
@@ -77,6 +78,8 @@ impl Context {
             #[derive(Clone, Debug, Default, Eq, PartialEq, #ethers_contract::EthError, #ethers_contract::EthDisplay, #derives)]
             #[etherror( name = #error_name, abi = #abi_signature )]
             pub #data_type_definition
+
+            impl ::std::error::Error for #error_ident {}
         })
     }
 
@@ -115,6 +118,13 @@ impl Context {
                 #(#variants(#variants)),*
             }
 
+        impl #enum_name {
+            /// Decodes the revert data of a failed call into the matching custom error
+            pub fn decode_revert(data: impl AsRef<[u8]>) -> ::std::result::Result<Self, #ethers_core::abi::AbiError> {
+                <Self as #ethers_core::abi::AbiDecode>::decode(data)
+            }
+        }
+
         impl  #ethers_core::abi::AbiDecode for #enum_name {
             fn decode(data: impl AsRef<[u8]>) -> ::std::result::Result<Self, #ethers_core::abi::AbiError> {
                  #(
@@ -136,16 +146,20 @@ impl Context {
             }
         }
 
+        /// Formats the error with its name and fields, e.g.
+        /// `InsufficientBalance { available: 1, required: 2 }`
         impl ::std::fmt::Display for #enum_name {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 match self {
                     #(
-                        #enum_name::#variants(element) => element.fmt(f)
+                        #enum_name::#variants(element) => ::std::fmt::Debug::fmt(element, f)
                     ),*
                 }
             }
         }
 
+        impl ::std::error::Error for #enum_name {}
+
         #(
             impl ::std::convert::From<#variants> for #enum_name {
                 fn from(var: #variants) -> Self {
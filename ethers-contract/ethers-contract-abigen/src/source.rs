@@ -254,14 +254,18 @@ fn get_local_contract(path: impl AsRef<str>) -> Result<String> {
     let path = if path.is_relative() {
         let manifest_path = env::var("CARGO_MANIFEST_DIR")?;
         let root = Path::new(&manifest_path);
-        let mut contract_path = root.join(&path);
-        if !contract_path.exists() {
-            contract_path = dunce::canonicalize(&path)?;
+        let contract_path = root.join(&path);
+        if contract_path.exists() {
+            contract_path
+        } else if let Ok(cwd_path) = dunce::canonicalize(&path) {
+            cwd_path
+        } else {
+            eyre::bail!(
+                "Unable to find local contract \"{}\", relative paths are resolved against the crate root, expected \"{}\"",
+                path.display(),
+                contract_path.display()
+            )
         }
-        if !contract_path.exists() {
-            eyre::bail!("Unable to find local contract \"{}\"", path.display())
-        }
-        contract_path
     } else {
         path
     };
@@ -329,6 +333,13 @@ fn get_npm_contract(package: &str) -> Result<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn missing_local_contract_names_the_path() {
+        let err = Source::local("does/not/exist.json").get().unwrap_err().to_string();
+        let expected = Path::new(env!("CARGO_MANIFEST_DIR")).join("does/not/exist.json");
+        assert!(err.contains(&expected.display().to_string()), "{err}");
+    }
+
     #[test]
     fn parse_source() {
         let root = "/rooted";
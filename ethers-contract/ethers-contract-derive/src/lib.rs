@@ -41,8 +41,18 @@ pub(crate) mod utils;
 ///
 /// // npmjs
 /// abigen!(MyContract, "npm:@org/package@1.0.0/path/to/contract.json");
+///
+/// // inline JSON ABI
+/// abigen!(MyContract, r#"[{"type":"function","name":"x","inputs":[],"outputs":[]}]"#);
+///
+/// // inline human readable ABI
+/// abigen!(MyContract, r#"[function x() external]"#);
 /// ```
 ///
+/// The bindings are generated in a module named after the contract in snake case, e.g.
+/// `my_contract`, whose items are re-exported. A source that can not be found or parsed is
+/// reported as a compile error.
+///
 /// Note that Etherscan rate-limits requests to their API, to avoid this an
 /// `ETHERSCAN_API_KEY` environment variable can be set. If it is, it will use
 /// that API key when retrieving the contract ABI.
@@ -136,6 +136,25 @@ impl BaseContract {
         decode_event_raw(event, topics, data)
     }
 
+    /// Decodes a log of any of the contract's events, given the `log.topics` and `log.data`
+    /// fields from the transaction receipt
+    ///
+    /// The event is looked up by the log's topic0, so anonymous events can not be decoded.
+    /// Returns the name of the event and its decoded parameters.
+    pub fn decode_any_event_raw(
+        &self,
+        topics: Vec<H256>,
+        data: Bytes,
+    ) -> Result<(String, Vec<Token>), AbiError> {
+        let topic0 = topics.first().copied().unwrap_or_default();
+        let event = self
+            .abi
+            .events()
+            .find(|event| !event.anonymous && event.signature() == topic0)
+            .ok_or_else(|| Error::InvalidName(hex::encode(topic0)))?;
+        Ok((event.name.clone(), decode_event_raw(event, topics, data)?))
+    }
+
     /// Decodes the provided ABI encoded bytes with the selected function selector
     ///
     /// Returns a [`Token`] vector, which lets you decode function arguments dynamically
@@ -234,8 +253,7 @@ pub fn decode_event<D: Detokenize>(
 
 /// Helper for ABI encoding arguments for a specific function
 pub fn encode_function_data<T: Tokenize>(function: &Function, args: T) -> Result<Bytes, AbiError> {
-    let tokens = args.into_tokens();
-    Ok(function.encode_input(&tokens).map(Into::into)?)
+    function.encode_call(&args.into_tokens())
 }
 
 /// Helper for ABI decoding raw data based on a function's input or output.
@@ -245,14 +263,11 @@ pub fn decode_function_data_raw<T: AsRef<[u8]>>(
     is_input: bool,
 ) -> Result<Vec<Token>, AbiError> {
     let bytes = bytes.as_ref();
-    Ok(if is_input {
-        if bytes.len() < 4 || bytes[..4] != function.selector() {
-            return Err(AbiError::WrongSelector)
-        }
-        function.decode_input(&bytes[4..])?
+    if is_input {
+        function.decode_call(bytes)
     } else {
-        function.decode_output(bytes)?
-    })
+        Ok(function.decode_output(bytes)?)
+    }
 }
 
 /// Helper for ABI decoding raw data based on a function's input or output.
@@ -338,4 +353,30 @@ mod tests {
         assert_eq!(owner, "e4e60fdf9bf188fa57b7a5022230363d5bd56d08".parse::<Address>().unwrap());
         assert_eq!(spender, "7a250d5630b4cf539739df2c5dacb4c659f2488d".parse::<Address>().unwrap());
     }
+
+    #[test]
+    fn can_parse_any_event() {
+        let abi = BaseContract::from(
+            parse_abi(&[
+                "event Approval(address indexed owner, address indexed spender, uint256 value)",
+                "event Transfer(address indexed from, address indexed to, uint256 value)",
+            ])
+            .unwrap(),
+        );
+        let (from, to) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+        let transfer = abi.abi().event("Transfer").unwrap().signature();
+        let data = Bytes::from(ethers_core::abi::encode(&[Token::Uint(100u64.into())]));
+
+        let (name, tokens) =
+            abi.decode_any_event_raw(vec![transfer, from.into(), to.into()], data.clone()).unwrap();
+        assert_eq!(name, "Transfer");
+        assert_eq!(
+            tokens,
+            vec![Token::Address(from), Token::Address(to), Token::Uint(100u64.into())]
+        );
+
+        // unknown events and logs without topics are rejected
+        assert!(abi.decode_any_event_raw(vec![H256::zero()], data.clone()).is_err());
+        assert!(abi.decode_any_event_raw(vec![], data).is_err());
+    }
 }
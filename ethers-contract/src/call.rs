@@ -11,7 +11,7 @@ use ethers_core::{
 };
 use ethers_providers::{
     call_raw::{CallBuilder, RawCall},
-    Middleware, PendingTransaction, ProviderError,
+    Middleware, PendingTransaction, ProviderError, RevertInfo,
 };
 
 use std::{
@@ -71,6 +71,25 @@ pub enum ContractError<M: Middleware> {
     /// receipt
     #[error("Contract was not deployed")]
     ContractNotDeployed,
+
+    /// Thrown when the simulation of a transaction reverts, see [`ContractCall::simulate`]
+    #[error("simulation reverted: {0}")]
+    Reverted(RevertInfo),
+
+    /// Thrown when a deployment is validated that has no bytecode, see
+    /// [`Deployer::validate`](crate::Deployer::validate)
+    #[error("the deployment transaction has no bytecode")]
+    EmptyBytecode,
+}
+
+impl<M: Middleware> ContractError<M> {
+    /// Converts the error of a simulating `eth_call`, reporting reverts as [`Self::Reverted`]
+    pub(crate) fn from_simulation(err: M::Error) -> Self {
+        match M::as_error_response(&err).and_then(RevertInfo::from_error_response) {
+            Some(revert) => ContractError::Reverted(revert),
+            None => ContractError::MiddlewareError(err),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -138,11 +157,26 @@ impl<M, D: Detokenize> ContractCall<M, D> {
         self
     }
 
-    /// Sets the `block` field for sending the tx to the chain
+    /// Sets the block the call and gas estimation are executed against, `latest` if unset
     pub fn block<T: Into<BlockId>>(mut self, block: T) -> Self {
         self.block = Some(block.into());
         self
     }
+
+    /// Returns the ABI encoded calldata of the call, i.e. the function selector followed by the
+    /// encoded arguments, e.g. to relay it in a meta transaction.
+    ///
+    /// Unlike [`calldata`](Self::calldata) this does not require a [`Middleware`] and returns
+    /// empty bytes if the transaction has no data.
+    pub fn encode(&self) -> Bytes {
+        self.tx.data().cloned().unwrap_or_default()
+    }
+
+    /// Decodes the return data of the function, e.g. the result of an `eth_call` that was not
+    /// made through this call.
+    pub fn decode_output(&self, raw: &[u8]) -> Result<D, AbiError> {
+        decode_function_data(&self.function, raw, false)
+    }
 }
 
 impl<M, D> ContractCall<M, D>
@@ -170,15 +204,26 @@ where
     ///
     /// Note: this function _does not_ send a transaction from your account
     pub async fn call(&self) -> Result<D, ContractError<M>> {
-        let bytes =
-            self.client.call(&self.tx, self.block).await.map_err(ContractError::MiddlewareError)?;
+        let bytes = self.call_bytes().await?;
 
         // decode output
-        let data = decode_function_data(&self.function, &bytes, false)?;
+        let data = self.decode_output(&bytes)?;
 
         Ok(data)
     }
 
+    /// Queries the blockchain via an `eth_call` for the provided transaction, like
+    /// [`call`](Self::call), but returns the raw return data without ABI decoding it.
+    ///
+    /// This is useful to inspect the response if decoding fails, e.g. because the ABI does not
+    /// match the deployed contract. Unlike [`call_raw_bytes`](Self::call_raw_bytes) the request
+    /// goes through the client's middleware stack.
+    ///
+    /// Note: this function _does not_ send a transaction from your account
+    pub async fn call_bytes(&self) -> Result<Bytes, ContractError<M>> {
+        self.client.call(&self.tx, self.block).await.map_err(ContractError::MiddlewareError)
+    }
+
     /// Returns an implementer of [`RawCall`] which can be `.await`d to query the blockchain via
     /// `eth_call`, returning the deoded return data.
     ///
@@ -217,6 +262,32 @@ where
             .await
             .map_err(ContractError::MiddlewareError)
     }
+
+    /// Executes the transaction via `eth_call` through the client's middleware stack with the same
+    /// calldata and value, sent from the client's default sender unless `from` is set, and returns
+    /// the raw return data.
+    ///
+    /// Nothing is signed or broadcast. If the call reverts this fails with
+    /// [`ContractError::Reverted`], which carries the revert data reported by the node.
+    pub async fn simulate(&self) -> Result<Bytes, ContractError<M>> {
+        let mut tx = self.tx.clone();
+        if tx.from().is_none() {
+            if let Some(from) = self.client.default_sender() {
+                tx.set_from(from);
+            }
+        }
+
+        self.client.call(&tx, self.block).await.map_err(ContractError::from_simulation)
+    }
+
+    /// Simulates the transaction with [`simulate`](Self::simulate) and only signs and broadcasts
+    /// it if the simulation succeeds, to not pay gas for a transaction that would revert.
+    pub async fn send_with_simulation(
+        &self,
+    ) -> Result<PendingTransaction<'_, M::Provider>, ContractError<M>> {
+        self.simulate().await?;
+        self.send().await
+    }
 }
 
 /// [`ContractCall`] can be turned into [`Future`] automatically with `.await`.
@@ -1,13 +1,15 @@
 use crate::{
     base::{encode_function_data, AbiError, BaseContract},
-    call::ContractCall,
+    call::{ContractCall, ContractError},
     event::{EthEvent, Event},
+    stream::EventStream,
     EthLogDecode,
 };
 
 use ethers_core::{
-    abi::{Abi, Detokenize, Error, EventExt, Function, Tokenize},
-    types::{Address, Filter, Selector, ValueOrArray},
+    abi::{Abi, Detokenize, Error, EventExt, Function, Token, Tokenize},
+    types::{Address, Filter, Log, Selector, ValueOrArray, H256},
+    utils::keccak256,
 };
 
 #[cfg(not(feature = "legacy"))]
@@ -15,10 +17,26 @@ use ethers_core::types::Eip1559TransactionRequest;
 #[cfg(feature = "legacy")]
 use ethers_core::types::TransactionRequest;
 
-use ethers_providers::Middleware;
+use ethers_providers::{FilterWatcher, Middleware};
 
 use std::{fmt::Debug, marker::PhantomData, sync::Arc};
 
+/// Stream of all events of a contract as returned by [`Contract::all_events`], yielding the name
+/// and decoded parameters of each event
+pub type AllEventsStream<'a, M> = EventStream<
+    'a,
+    FilterWatcher<'a, <M as Middleware>::Provider, Log>,
+    (String, Vec<Token>),
+    ContractError<M>,
+>;
+
+/// The storage slot holding the implementation address of an EIP-1967 proxy, i.e.
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+pub const EIP1967_IMPLEMENTATION_SLOT: H256 = H256([
+    0x36, 0x08, 0x94, 0xa1, 0x3b, 0xa1, 0xa3, 0x21, 0x06, 0x67, 0xc8, 0x28, 0x49, 0x2d, 0xb9, 0x8d,
+    0xca, 0x3e, 0x20, 0x76, 0xcc, 0x37, 0x35, 0xa9, 0x20, 0xa3, 0xca, 0x50, 0x5d, 0x38, 0x2b, 0xbc,
+]);
+
 /// A Contract is an abstraction of an executable program on the Ethereum Blockchain.
 /// It has code (called byte code) as well as allocated long-term memory
 /// (called storage). Every deployed Contract has an address, which is used to connect
@@ -228,6 +246,39 @@ impl<M: Middleware> Contract<M> {
         }
     }
 
+    /// Queries the logs of all of the contract's events matching the filter, e.g. to monitor every
+    /// event of the contract in a range of blocks. The filter's address is set to the contract's.
+    ///
+    /// Returns the name and decoded parameters of each event, see
+    /// [`BaseContract::decode_any_event_raw`]. Contracts generated by `abigen!` provide typed
+    /// access to all events via their `events` method instead.
+    pub async fn query_all_events(
+        &self,
+        filter: Filter,
+    ) -> Result<Vec<(String, Vec<Token>)>, ContractError<M>> {
+        let filter = filter.address(ValueOrArray::Value(self.address));
+        let logs = self.client.get_logs(&filter).await.map_err(ContractError::MiddlewareError)?;
+        logs.into_iter()
+            .map(|log| Ok(self.base_contract.decode_any_event_raw(log.topics, log.data)?))
+            .collect()
+    }
+
+    /// Watches all of the contract's events, i.e. installs a filter for the contract's address
+    /// without a topic0 and yields the name and decoded parameters of each new event, see
+    /// [`BaseContract::decode_any_event_raw`]. Contracts generated by `abigen!` provide typed
+    /// access to all events via their `events` method instead.
+    pub async fn all_events(&self) -> Result<AllEventsStream<'_, M>, ContractError<M>> {
+        let filter = Filter::new().address(ValueOrArray::Value(self.address));
+        let watcher = self.client.watch(&filter).await.map_err(ContractError::MiddlewareError)?;
+        Ok(EventStream::new(
+            watcher.id,
+            watcher,
+            Box::new(move |log| {
+                Ok(self.base_contract.decode_any_event_raw(log.topics, log.data)?)
+            }),
+        ))
+    }
+
     /// Returns an [`Event`](crate::builders::Event) builder with the provided name.
     pub fn event_for_name<D: EthLogDecode>(&self, name: &str) -> Result<Event<M, D>, Error> {
         // get the event's full name
@@ -240,12 +291,12 @@ impl<M: Middleware> Contract<M> {
     /// the `method_hash` method instead, since this will use the first match.
     pub fn method<T: Tokenize, D: Detokenize>(
         &self,
-        name: &str,
+        name: impl AsRef<str>,
         args: T,
     ) -> Result<ContractCall<M, D>, AbiError> {
         // get the function
-        let function = self.base_contract.abi.function(name)?;
-        self.method_func(function, args)
+        let function = self.base_contract.abi.function(name.as_ref())?;
+        self.method_with_function(function, args)
     }
 
     /// Returns a transaction builder for the selected function signature. This should be
@@ -261,10 +312,14 @@ impl<M: Middleware> Contract<M> {
             .get(&signature)
             .map(|(name, index)| &self.base_contract.abi.functions[name][*index])
             .ok_or_else(|| Error::InvalidName(hex::encode(signature)))?;
-        self.method_func(function, args)
+        self.method_with_function(function, args)
     }
 
-    fn method_func<T: Tokenize, D: Detokenize>(
+    /// Returns a transaction builder for an already resolved function, e.g. one that is called
+    /// repeatedly and should not be looked up in the ABI every time.
+    ///
+    /// Fails if the arguments do not match the function's inputs, before any request is made.
+    pub fn method_with_function<T: Tokenize, D: Detokenize>(
         &self,
         function: &Function,
         args: T,
@@ -295,14 +350,13 @@ impl<M: Middleware> Contract<M> {
         })
     }
 
-    /// Returns a new contract instance at `address`.
+    /// Returns a new contract instance at `address`, sharing the ABI and client of `self`.
     ///
-    /// Clones `self` internally
+    /// This is useful for factory-pattern contracts that deploy many identical instances.
+    ///
+    /// Clones `self` internally, the client is only reference counted
     #[must_use]
-    pub fn at<T: Into<Address>>(&self, address: T) -> Self
-    where
-        M: Clone,
-    {
+    pub fn at<T: Into<Address>>(&self, address: T) -> Self {
         let mut this = self.clone();
         this.address = address.into();
         this
@@ -312,10 +366,42 @@ impl<M: Middleware> Contract<M> {
     ///
     /// Clones `self` internally
     #[must_use]
-    pub fn connect<N>(&self, client: Arc<N>) -> Contract<N>
-    where
-        N: Clone,
-    {
+    pub fn connect<N>(&self, client: Arc<N>) -> Contract<N> {
         Contract { base_contract: self.base_contract.clone(), client, address: self.address }
     }
+
+    /// Returns the keccak256 hash of the code deployed at the contract's address
+    pub async fn deployed_code_hash(&self) -> Result<H256, ContractError<M>> {
+        let code = self
+            .client
+            .get_code(self.address, None)
+            .await
+            .map_err(ContractError::MiddlewareError)?;
+        Ok(keccak256(code).into())
+    }
+
+    /// Returns true if the code deployed at the contract's address is `expected`, e.g. the
+    /// runtime bytecode of a verified build of the contract.
+    ///
+    /// Note that the runtime bytecode differs from the creation bytecode used to deploy the
+    /// contract.
+    pub async fn verify_deployed_code(
+        &self,
+        expected: impl AsRef<[u8]>,
+    ) -> Result<bool, ContractError<M>> {
+        Ok(self.deployed_code_hash().await? == H256::from(keccak256(expected)))
+    }
+
+    /// Returns the implementation address of an EIP-1967 proxy deployed at the contract's
+    /// address, read from the [`EIP1967_IMPLEMENTATION_SLOT`].
+    ///
+    /// Returns the zero address if the contract is not such a proxy.
+    pub async fn implementation(&self) -> Result<Address, ContractError<M>> {
+        let slot = self
+            .client
+            .get_storage_at(self.address, EIP1967_IMPLEMENTATION_SLOT, None)
+            .await
+            .map_err(ContractError::MiddlewareError)?;
+        Ok(Address::from(slot))
+    }
 }
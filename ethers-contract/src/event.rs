@@ -3,9 +3,10 @@
 use crate::{log::LogMeta, stream::EventStream, ContractError, EthLogDecode};
 use ethers_core::{
     abi::{Address, Detokenize, RawLog},
-    types::{BlockNumber, Filter, Log, Topic, ValueOrArray, H256},
+    types::{BlockNumber, Filter, FilterBlockOption, Log, Topic, ValueOrArray, H256, U64},
 };
 use ethers_providers::{FilterWatcher, Middleware, PubsubClient, SubscriptionStream};
+use futures_util::{future, stream, Stream, StreamExt, TryStreamExt};
 use std::{borrow::Cow, marker::PhantomData};
 
 /// A trait for implementing event bindings
@@ -109,11 +110,18 @@ impl<M, D: EthLogDecode> Event<'_, M, D> {
         self
     }
 
-    /// Sets the filter's address.
-    pub fn address(mut self, address: ValueOrArray<Address>) -> Self {
+    /// Sets the filter's address, either a single `Address` or a `Vec<Address>` to match the
+    /// event of any of them
+    pub fn address<T: Into<ValueOrArray<Address>>>(mut self, address: T) -> Self {
         self.filter = self.filter.address(address);
         self
     }
+
+    /// Matches the event emitted by any of the given contracts, e.g. the same ERC-20 event across
+    /// many tokens
+    pub fn addresses(self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.address(addresses.into_iter().collect::<Vec<_>>())
+    }
 }
 
 impl<'a, M, D> Event<'a, M, D>
@@ -191,6 +199,106 @@ where
             }),
         ))
     }
+
+    /// Returns a `Stream` that first yields all past events starting at `from_block` and then
+    /// continues with new events as they are mined, like [`Self::stream_with_meta`].
+    ///
+    /// The past events are loaded via `eth_getLogs` in chunks of `page_size` blocks. The polling
+    /// filter for new events is only installed once they are loaded, as nodes drop filters that
+    /// are not polled for a while. The blocks mined in the meantime are loaded like the past
+    /// ones, after the filter is installed, and only events after them are taken from the
+    /// filter, so no event is skipped or yielded twice at the transition. The filter's
+    /// `to_block` is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "abigen")]
+    /// # async fn test<M:ethers_providers::Middleware>(contract: ethers_contract::Contract<M>) {
+    /// # use ethers_core::types::*;
+    /// # use futures_util::stream::StreamExt;
+    /// # use ethers_contract::{Contract, EthEvent};
+    /// #[derive(Clone, Debug, EthEvent)]
+    /// pub struct Transfer {
+    ///     #[ethevent(indexed)]
+    ///     pub from: Address,
+    ///     #[ethevent(indexed)]
+    ///     pub to: Address,
+    ///     pub tokens: U256,
+    /// }
+    ///
+    /// let ev = contract.event::<Transfer>();
+    /// let mut stream = ev.stream_from_block_with_meta(15_000_000, 10_000).await.unwrap();
+    /// while let Some(Ok((transfer, meta))) = stream.next().await {
+    ///     println!("{transfer:?} in block {}", meta.block_number);
+    /// }
+    /// # }
+    /// ```
+    pub async fn stream_from_block_with_meta(
+        &'a self,
+        from_block: impl Into<U64>,
+        page_size: u64,
+    ) -> Result<
+        impl Stream<Item = Result<(D, LogMeta), ContractError<M>>> + Unpin + 'a,
+        ContractError<M>,
+    > {
+        let head =
+            self.provider.get_block_number().await.map_err(ContractError::MiddlewareError)?;
+        let page_size = page_size.max(1);
+        let history = self.logs_in_pages(from_block.into().as_u64(), head.as_u64(), page_size);
+
+        let live = stream::once(Box::pin(async move {
+            let live_filter = self
+                .filter
+                .clone()
+                .select(FilterBlockOption::Range { from_block: None, to_block: None });
+            let live =
+                self.provider.watch(&live_filter).await.map_err(ContractError::MiddlewareError)?;
+            let last_block =
+                self.provider.get_block_number().await.map_err(ContractError::MiddlewareError)?;
+
+            // the blocks mined while loading the history
+            let gap = self.logs_in_pages(head.as_u64() + 1, last_block.as_u64(), page_size);
+            let live = live
+                .filter(move |log| {
+                    future::ready(log.block_number.map_or(true, |number| number > last_block))
+                })
+                .map(Ok);
+            Ok::<_, ContractError<M>>(gap.chain(live))
+        }))
+        .try_flatten();
+
+        Ok(history.chain(live).map(move |log: Result<Log, ContractError<M>>| {
+            let log = log?;
+            let meta = LogMeta::from(&log);
+            Ok((self.parse_log(log)?, meta))
+        }))
+    }
+
+    /// Loads the logs of the blocks `first..=last` via `eth_getLogs`, `page_size` blocks at a time
+    fn logs_in_pages(
+        &'a self,
+        first: u64,
+        last: u64,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<Log, ContractError<M>>> + Unpin + 'a {
+        let pages = (first..=last)
+            .step_by(page_size as usize)
+            .map(move |start| (start, start.saturating_add(page_size - 1).min(last)));
+
+        stream::iter(pages)
+            .then(move |(start, end)| {
+                let filter = self.filter.clone().from_block(start).to_block(end);
+                Box::pin(async move {
+                    self.provider
+                        .get_logs(&filter)
+                        .await
+                        .map_err(ContractError::<M>::MiddlewareError)
+                })
+            })
+            .map_ok(|logs| stream::iter(logs.into_iter().map(Ok)))
+            .try_flatten()
+    }
 }
 
 impl<'a, M, D> Event<'a, M, D>
@@ -109,6 +109,17 @@ impl<M: Middleware, C: From<Contract<M>>> ContractDeployer<M, C> {
         self
     }
 
+    /// Sends the deployment without [validating](Deployer::validate) it first
+    pub fn skip_validation(mut self) -> Self {
+        self.deployer = self.deployer.skip_validation();
+        self
+    }
+
+    /// Checks that the deployment would succeed, see [`Deployer::validate`]
+    pub async fn validate(&self) -> Result<(), ContractError<M>> {
+        self.deployer.validate().await
+    }
+
     /// Dry runs the deployment of the contract
     ///
     /// Note: this function _does not_ send a transaction from your account
@@ -160,9 +171,11 @@ pub struct Deployer<M> {
     /// The deployer's transaction, exposed for overriding the defaults
     pub tx: TypedTransaction,
     abi: Abi,
+    bytecode: Bytes,
     client: Arc<M>,
     confs: usize,
     block: BlockNumber,
+    validate: bool,
 }
 
 impl<M> Clone for Deployer<M> {
@@ -170,9 +183,11 @@ impl<M> Clone for Deployer<M> {
         Deployer {
             tx: self.tx.clone(),
             abi: self.abi.clone(),
+            bytecode: self.bytecode.clone(),
             client: self.client.clone(),
             confs: self.confs,
             block: self.block,
+            validate: self.validate,
         }
     }
 }
@@ -201,6 +216,40 @@ impl<M: Middleware> Deployer<M> {
         self
     }
 
+    /// Sends the deployment without [validating](Self::validate) it first, e.g. if the node does
+    /// not support `eth_call` for deployments
+    pub fn skip_validation(mut self) -> Self {
+        self.validate = false;
+        self
+    }
+
+    /// Checks that the deployment would succeed, which [`send`](Self::send) does unless
+    /// [`skip_validation`](Self::skip_validation) is set.
+    ///
+    /// Fails with [`ContractError::EmptyBytecode`] if there is no bytecode to deploy. Otherwise the
+    /// deployment is simulated via `eth_call`, sent from the client's default sender unless `from`
+    /// is set, and a reverting constructor is reported as [`ContractError::Reverted`]. Invalid
+    /// constructor arguments are already rejected when the deployer is created.
+    ///
+    /// Note: this function _does not_ send a transaction from your account
+    pub async fn validate(&self) -> Result<(), ContractError<M>> {
+        if self.bytecode.is_empty() {
+            return Err(ContractError::EmptyBytecode)
+        }
+
+        let mut tx = self.tx.clone();
+        if tx.from().is_none() {
+            if let Some(from) = self.client.default_sender() {
+                tx.set_from(from);
+            }
+        }
+        self.client
+            .call(&tx, Some(self.block.into()))
+            .await
+            .map_err(ContractError::from_simulation)?;
+        Ok(())
+    }
+
     /// Dry runs the deployment of the contract
     ///
     /// Note: this function _does not_ send a transaction from your account
@@ -238,6 +287,10 @@ impl<M: Middleware> Deployer<M> {
     pub async fn send_with_receipt(
         self,
     ) -> Result<(Contract<M>, TransactionReceipt), ContractError<M>> {
+        if self.validate {
+            self.validate().await?;
+        }
+
         let pending_tx = self
             .client
             .send_transaction(self.tx, Some(self.block.into()))
@@ -355,9 +408,11 @@ impl<M: Middleware> ContractFactory<M> {
         Ok(Deployer {
             client: Arc::clone(&self.client), // cheap clone behind the arc
             abi: self.abi,
+            bytecode: self.bytecode,
             tx,
             confs: 1,
             block: BlockNumber::Latest,
+            validate: true,
         })
     }
 
@@ -3,7 +3,7 @@
 #![deny(unsafe_code)]
 
 mod contract;
-pub use contract::Contract;
+pub use contract::{AllEventsStream, Contract, EIP1967_IMPLEMENTATION_SLOT};
 
 mod base;
 pub use base::{decode_function_data, encode_function_data, AbiError, BaseContract};
@@ -23,6 +23,9 @@ pub use event::{EthEvent, Event};
 mod log;
 pub use log::{decode_logs, EthLogDecode, LogMeta};
 
+mod transaction;
+pub use transaction::TransactionBuilder;
+
 pub mod stream;
 
 #[cfg(any(test, feature = "abigen"))]
@@ -1,8 +1,9 @@
 //! Mod of types for ethereum logs
 use ethers_core::{
     abi::{Error, RawLog},
-    types::{Address, Log, TxHash, H256, U256, U64},
+    types::{Address, Log, Transaction, TxHash, H256, U256, U64},
 };
+use ethers_providers::Middleware;
 use serde::{Deserialize, Serialize};
 
 /// A trait for types (events) that can be decoded from a `RawLog`
@@ -40,6 +41,17 @@ pub struct LogMeta {
     pub log_index: U256,
 }
 
+impl LogMeta {
+    /// Fetches the transaction that emitted the log, `None` if the node does not know it (anymore),
+    /// e.g. after a reorg
+    pub async fn transaction<M: Middleware>(
+        &self,
+        client: &M,
+    ) -> Result<Option<Transaction>, M::Error> {
+        client.get_transaction(self.transaction_hash).await
+    }
+}
+
 impl From<&Log> for LogMeta {
     fn from(src: &Log) -> Self {
         LogMeta {
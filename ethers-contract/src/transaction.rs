@@ -0,0 +1,176 @@
+use ethers_core::{
+    abi::{Function, FunctionExt, Token},
+    types::{
+        transaction::{
+            eip2718::TypedTransaction,
+            eip2930::{AccessList, AccessListItem},
+        },
+        Address, H256, U256,
+    },
+};
+
+#[cfg(not(feature = "legacy"))]
+use ethers_core::types::Eip1559TransactionRequest;
+#[cfg(feature = "legacy")]
+use ethers_core::types::{transaction::eip2930::Eip2930TransactionRequest, TransactionRequest};
+
+use crate::AbiError;
+
+/// Builds a transaction calling a contract function step by step, without a
+/// [`Contract`](crate::Contract) instance or a client.
+///
+/// The arguments are only ABI encoded by [`TransactionBuilder::build`], so the call can be put
+/// together from tokens computed at different stages, e.g. the hops of a swap through a router.
+///
+/// # Example
+///
+/// ```
+/// use ethers_contract::TransactionBuilder;
+/// use ethers_core::{
+///     abi::{parse_abi, Token},
+///     types::{Address, H256, U256},
+/// };
+///
+/// let abi = parse_abi(&["function deposit(address to) payable"]).unwrap();
+/// let weth = Address::random();
+/// let to = Address::random();
+///
+/// let tx = TransactionBuilder::new()
+///     .call(weth, abi.function("deposit").unwrap(), &[Token::Address(to)])
+///     .value(U256::exp10(18))
+///     .gas_limit(50_000u64)
+///     .add_access_list_entry(weth, vec![H256::zero()])
+///     .build()
+///     .unwrap();
+/// assert_eq!(tx.to_addr(), Some(&weth));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TransactionBuilder {
+    call: Option<(Address, Function, Vec<Token>)>,
+    value: Option<U256>,
+    gas: Option<U256>,
+    access_list: AccessList,
+}
+
+impl TransactionBuilder {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `function` of the contract at `contract` with `args`, replacing any previous call
+    #[must_use]
+    pub fn call(mut self, contract: Address, function: &Function, args: &[Token]) -> Self {
+        self.call = Some((contract, function.clone(), args.to_vec()));
+        self
+    }
+
+    /// Sets the value sent with the transaction
+    #[must_use]
+    pub fn value<T: Into<U256>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the gas limit of the transaction
+    #[must_use]
+    pub fn gas_limit<T: Into<U256>>(mut self, gas: T) -> Self {
+        self.gas = Some(gas.into());
+        self
+    }
+
+    /// Adds the storage slots of `address` to the transaction's access list
+    #[must_use]
+    pub fn add_access_list_entry(mut self, address: Address, slots: Vec<H256>) -> Self {
+        self.access_list.0.push(AccessListItem { address, storage_keys: slots });
+        self
+    }
+
+    /// ABI encodes the call and returns the transaction.
+    ///
+    /// Fails if the arguments do not match the function's inputs. Fields that are not set, like
+    /// the nonce and gas prices, are left to be filled by the client sending the transaction.
+    pub fn build(self) -> Result<TypedTransaction, AbiError> {
+        let (to, data) = match self.call {
+            Some((contract, function, args)) => {
+                (Some(contract.into()), Some(function.encode_call(&args)?))
+            }
+            None => (None, None),
+        };
+
+        #[cfg(feature = "legacy")]
+        let tx = {
+            let tx = TransactionRequest {
+                to,
+                data,
+                value: self.value,
+                gas: self.gas,
+                ..Default::default()
+            };
+            if self.access_list.0.is_empty() {
+                tx.into()
+            } else {
+                // legacy transactions can not carry an access list
+                Eip2930TransactionRequest::new(tx, self.access_list).into()
+            }
+        };
+        #[cfg(not(feature = "legacy"))]
+        let tx = Eip1559TransactionRequest {
+            to,
+            data,
+            value: self.value,
+            gas: self.gas,
+            access_list: self.access_list,
+            ..Default::default()
+        }
+        .into();
+
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_core::abi::parse_abi;
+
+    #[test]
+    fn builds_contract_call() {
+        let abi = parse_abi(&["function swap(uint256 amountIn, address[] path)"]).unwrap();
+        let function = abi.function("swap").unwrap();
+        let router = Address::repeat_byte(0x11);
+        let path = vec![Token::Address(Address::repeat_byte(0x22)), Token::Address(router)];
+        let args = [Token::Uint(100u64.into()), Token::Array(path)];
+
+        let tx = TransactionBuilder::new()
+            .call(router, function, &args)
+            .value(1u64)
+            .gas_limit(100_000u64)
+            .add_access_list_entry(router, vec![H256::zero()])
+            .build()
+            .unwrap();
+
+        assert_eq!(tx.to_addr(), Some(&router));
+        assert_eq!(tx.data().unwrap(), &function.encode_call(&args).unwrap());
+        assert_eq!(tx.value(), Some(&1u64.into()));
+        assert_eq!(tx.gas(), Some(&100_000u64.into()));
+        assert_eq!(
+            tx.access_list(),
+            Some(&AccessList(vec![AccessListItem {
+                address: router,
+                storage_keys: vec![H256::zero()]
+            }]))
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_args() {
+        let abi = parse_abi(&["function transfer(address to, uint256 amount)"]).unwrap();
+        let builder = TransactionBuilder::new().call(
+            Address::zero(),
+            abi.function("transfer").unwrap(),
+            &[Token::Bool(true)],
+        );
+        assert!(builder.build().is_err());
+    }
+}
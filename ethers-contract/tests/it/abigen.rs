@@ -658,6 +658,68 @@ fn can_gen_seaport() {
     });
 }
 
+#[test]
+fn can_decode_custom_error_revert() {
+    abigen!(
+        Vault,
+        r#"[
+            {"type":"error","name":"InsufficientBalance","inputs":[{"name":"available","type":"uint256"},{"name":"required","type":"uint256"}]},
+            {"type":"error","name":"Unauthorized","inputs":[]}
+        ]"#
+    );
+
+    let err = InsufficientBalance { available: 1u64.into(), required: 2u64.into() };
+    let data = err.clone().encode();
+    assert_eq!(&data[..4], &<InsufficientBalance as ethers_contract::EthError>::selector()[..]);
+
+    match VaultErrors::decode_revert(&data).unwrap() {
+        VaultErrors::InsufficientBalance(decoded) => assert_eq!(decoded, err),
+        VaultErrors::Unauthorized(_) => panic!("decoded the wrong error"),
+    }
+    assert_eq!(
+        VaultErrors::decode_revert(Unauthorized.encode()).unwrap(),
+        VaultErrors::Unauthorized(Unauthorized)
+    );
+    assert!(VaultErrors::decode_revert([0u8; 4]).is_err());
+}
+
+#[test]
+fn can_display_custom_error_revert() {
+    abigen!(
+        Bank,
+        r#"[
+            {"type":"error","name":"InsufficientBalance","inputs":[{"name":"account","type":"address"},{"name":"balance","type":"uint256"},{"name":"needed","type":"uint256"}]},
+            {"type":"error","name":"Paused","inputs":[]}
+        ]"#
+    );
+
+    // `InsufficientBalance(0x000000000000000000000000000000000000dEaD, 0, 1000)`
+    let data = hex::decode(concat!(
+        "db42144d",
+        "000000000000000000000000000000000000000000000000000000000000dead",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "00000000000000000000000000000000000000000000000000000000000003e8",
+    ))
+    .unwrap();
+    let err = BankErrors::decode(data).unwrap();
+    assert_eq!(
+        err,
+        BankErrors::InsufficientBalance(InsufficientBalance {
+            account: "0x000000000000000000000000000000000000dEaD".parse().unwrap(),
+            balance: 0u64.into(),
+            needed: 1000u64.into(),
+        })
+    );
+    assert_eq!(
+        err.to_string(),
+        "InsufficientBalance { account: 0x000000000000000000000000000000000000dead, balance: 0, needed: 1000 }"
+    );
+    assert_eq!(BankErrors::Paused(Paused).to_string(), "Paused");
+
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.source().is_none());
+}
+
 #[test]
 fn can_generate_to_string_overload() {
     abigen!(
@@ -750,3 +812,83 @@ fn can_handle_overloaded_function_with_array() {
     ]"#,
     );
 }
+
+#[tokio::test]
+async fn can_call_at_block() {
+    abigen!(
+        Erc20,
+        r#"[
+            function balanceOf(address account) external view returns (uint256)
+        ]"#,
+    );
+
+    let (provider, mock) = Provider::mocked();
+    let contract = Erc20::new(Address::zero(), Arc::new(provider));
+
+    let call = contract.balance_of(Address::zero()).block(12_000_000u64);
+    let output: ethers_core::types::Bytes = U256::from(42).encode().into();
+    mock.push::<ethers_core::types::Bytes, _>(&output).unwrap();
+    let balance = call.call().await.unwrap();
+    assert_eq!(balance, 42.into());
+
+    mock.assert_request("eth_call", (&call.tx, ethers_core::types::U64::from(12_000_000))).unwrap();
+}
+
+#[tokio::test]
+async fn can_call_contract_from_spawned_task() {
+    abigen!(
+        Erc20,
+        r#"[
+            function balanceOf(address account) external view returns (uint256)
+        ]"#,
+    );
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let (provider, mock) = Provider::mocked();
+    let contract = Erc20::new(Address::zero(), Arc::new(provider));
+    assert_send_sync(&contract);
+
+    let output: ethers_core::types::Bytes = U256::from(42).encode().into();
+    mock.push::<ethers_core::types::Bytes, _>(&output).unwrap();
+    let balance = tokio::spawn(async move { contract.balance_of(Address::zero()).call().await })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(balance, 42.into());
+}
+
+#[test]
+fn can_gen_human_readable_inline_tuples() {
+    abigen!(
+        SwapRouter,
+        r#"[
+        function exactInputSingle((address tokenIn, address tokenOut, uint24 fee, address recipient, uint256 deadline, uint256 amountIn, uint256 amountOutMinimum, uint160 sqrtPriceLimitX96) params) external payable returns (uint256 amountOut)
+    ]"#,
+    );
+    assert_call::<ExactInputSingleCall>();
+    assert_eq!(
+        ExactInputSingleCall::abi_signature(),
+        "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))"
+    );
+
+    // inline tuples are generated as rust tuples
+    let params: (Address, Address, u32, Address, U256, U256, U256, U256) = (
+        Address::random(),
+        Address::random(),
+        3000,
+        Address::random(),
+        U256::MAX,
+        100u64.into(),
+        1u64.into(),
+        U256::zero(),
+    );
+    let call = ExactInputSingleCall { params };
+    let encoded = call.clone().encode();
+    assert_eq!(&encoded[..4], &[0x41, 0x4b, 0xf3, 0x89]);
+    assert_eq!(ExactInputSingleCall::decode(encoded).unwrap(), call);
+
+    let (client, _mock) = Provider::mocked();
+    let contract = SwapRouter::new(Address::default(), Arc::new(client));
+    let _ = contract.exact_input_single(params);
+}
@@ -886,4 +886,396 @@ mod eth_tests {
 
         assert!(verify, "typed data signature failed!");
     }
+
+    #[tokio::test]
+    async fn method_with_function_validates_args() {
+        use ethers_contract::Contract;
+        use ethers_core::abi::parse_abi;
+
+        let (provider, mock) = Provider::mocked();
+        let abi = parse_abi(&["function getValue(uint256) view returns (uint256)"]).unwrap();
+        let function = abi.function("getValue").unwrap().clone();
+        let contract = Contract::new(Address::zero(), abi, provider);
+
+        let call = contract.method_with_function::<_, U256>(&function, U256::from(1u64)).unwrap();
+        let by_name =
+            contract.method::<_, U256>(String::from("getValue"), U256::from(1u64)).unwrap();
+        assert_eq!(call.calldata(), by_name.calldata());
+        assert_eq!(&call.calldata().unwrap()[..4], &function.short_signature()[..]);
+
+        // mismatching arguments are rejected before any request is made
+        assert!(contract.method_with_function::<_, U256>(&function, "hi".to_string()).is_err());
+        assert!(contract.method_with_function::<_, U256>(&function, ()).is_err());
+        mock.assert_request("eth_call", ()).unwrap_err();
+    }
+
+    #[test]
+    fn at_shares_abi_and_client() {
+        use ethers_contract::Contract;
+        use ethers_core::abi::parse_abi;
+
+        // `at` must not require the client to be `Clone`
+        fn pair_at<M: ethers_providers::Middleware>(
+            factory: &Contract<M>,
+            pair: Address,
+        ) -> Contract<M> {
+            factory.at(pair)
+        }
+
+        let (provider, _mock) = Provider::mocked();
+        let abi =
+            parse_abi(&["function getReserves() view returns (uint112, uint112, uint32)"]).unwrap();
+        let contract = Contract::new(Address::zero(), abi, provider);
+
+        let pair = Address::repeat_byte(0x11);
+        let pair_contract = pair_at(&contract, pair);
+        assert_eq!(pair_contract.address(), pair);
+        assert_eq!(contract.address(), Address::zero());
+        assert_eq!(pair_contract.abi(), contract.abi());
+        assert!(Arc::ptr_eq(&pair_contract.client(), &contract.client()));
+    }
+
+    #[tokio::test]
+    async fn call_bytes_returns_undecoded_data() {
+        use ethers_contract::{Contract, ContractError};
+        use ethers_core::abi::parse_abi;
+
+        let (provider, mock) = Provider::mocked();
+        let abi = parse_abi(&["function getValue() view returns (uint256)"]).unwrap();
+        let contract = Contract::new(Address::zero(), abi, provider);
+        let call = contract.method::<_, U256>("getValue", ()).unwrap();
+
+        // not a valid `uint256`
+        let data = Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]);
+        mock.push::<Bytes, _>(data.clone()).unwrap();
+        mock.push::<Bytes, _>(data.clone()).unwrap();
+
+        assert_eq!(call.call_bytes().await.unwrap(), data);
+        assert!(matches!(call.call().await.unwrap_err(), ContractError::AbiError(_)));
+    }
+
+    #[tokio::test]
+    async fn stream_from_block_backfills_then_follows_new_events() {
+        use ethers_core::types::{Log, U64};
+
+        #[derive(Clone, Debug, PartialEq, EthEvent)]
+        struct ValueChanged {
+            #[ethevent(indexed)]
+            author: Address,
+            value: U256,
+        }
+
+        fn log(block: u64, value: u64) -> Log {
+            Log {
+                address: Address::repeat_byte(0x11),
+                topics: vec![ValueChanged::signature(), H256::from(Address::repeat_byte(0x22))],
+                data: encode(&[Token::Uint(value.into())]).into(),
+                block_hash: Some(H256::from_low_u64_be(block)),
+                block_number: Some(block.into()),
+                transaction_hash: Some(H256::from_low_u64_be(value)),
+                transaction_index: Some(0u64.into()),
+                log_index: Some(0u64.into()),
+                ..Default::default()
+            }
+        }
+
+        let (provider, mock) = Provider::mocked();
+        let provider = provider.interval(Duration::from_millis(10));
+
+        // responses are popped from the back
+        // the filter reports a log of a block that was loaded with `eth_getLogs`, it is skipped
+        mock.push::<Vec<Log>, _>(vec![log(17, 5), log(18, 6)]).unwrap();
+        // blocks 16 and 17 were mined while loading the history
+        mock.push::<Vec<Log>, _>(vec![log(16, 4), log(17, 5)]).unwrap();
+        mock.push(U64::from(17u64)).unwrap();
+        mock.push(U256::from(1u64)).unwrap();
+        mock.push::<Vec<Log>, _>(vec![log(15, 3)]).unwrap();
+        mock.push::<Vec<Log>, _>(vec![log(10, 1), log(12, 2)]).unwrap();
+        mock.push(U64::from(15u64)).unwrap();
+
+        let event = ValueChanged::new(Filter::new(), &provider);
+        let stream = event.stream_from_block_with_meta(10u64, 5).await.unwrap();
+        let events: Vec<_> = stream.take(6).map(Result::unwrap).collect().await;
+
+        let values: Vec<_> = events.iter().map(|(event, _)| event.value.as_u64()).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+        let blocks: Vec<_> = events.iter().map(|(_, meta)| meta.block_number.as_u64()).collect();
+        assert_eq!(blocks, vec![10, 12, 15, 16, 17, 18]);
+        assert!(events.iter().all(|(event, _)| event.author == Address::repeat_byte(0x22)));
+
+        // the filter is only installed once the history is loaded
+        let history = |from: u64, to: u64| [event.filter.clone().from_block(from).to_block(to)];
+        mock.assert_request("eth_blockNumber", ()).unwrap();
+        mock.assert_request("eth_getLogs", history(10, 14)).unwrap();
+        mock.assert_request("eth_getLogs", history(15, 15)).unwrap();
+        mock.assert_request("eth_newFilter", [event.filter.clone()]).unwrap();
+        mock.assert_request("eth_blockNumber", ()).unwrap();
+        mock.assert_request("eth_getLogs", history(16, 17)).unwrap();
+    }
+
+    #[test]
+    fn encodes_call_and_decodes_output_offline() {
+        use ethers_contract::Contract;
+        use ethers_core::abi::parse_abi;
+
+        let (provider, _mock) = Provider::mocked();
+        let abi = parse_abi(&["function balanceOf(address owner) view returns (uint256)"]).unwrap();
+        let contract = Contract::new(Address::zero(), abi, provider);
+        let owner = Address::random();
+        let call = contract.method::<_, U256>("balanceOf", owner).unwrap();
+
+        let calldata = call.encode();
+        assert_eq!(&calldata[..4], &[0x70, 0xa0, 0x82, 0x31]);
+        assert_eq!(&calldata[4..], &encode(&[Token::Address(owner)])[..]);
+
+        let output = encode(&[Token::Uint(1337u64.into())]);
+        assert_eq!(call.decode_output(&output).unwrap(), U256::from(1337u64));
+        assert!(call.decode_output(&output[..31]).is_err());
+    }
+
+    #[test]
+    fn event_filter_matches_multiple_addresses() {
+        use ethers_contract::Contract;
+
+        #[derive(Clone, Debug, EthEvent)]
+        struct Transfer {
+            #[ethevent(indexed)]
+            from: Address,
+            #[ethevent(indexed)]
+            to: Address,
+            value: U256,
+        }
+
+        let (provider, _mock) = Provider::mocked();
+        let provider = Arc::new(provider);
+        let tokens: Vec<Address> = (1..=5u64).map(Address::from_low_u64_be).collect();
+
+        let event = Contract::event_of_type::<Transfer>(&provider).addresses(tokens.clone());
+        let json = serde_json::to_value(event.filter).unwrap();
+        assert_eq!(json["address"], serde_json::to_value(&tokens).unwrap());
+        assert_eq!(json["address"].as_array().unwrap().len(), 5);
+
+        // a single address is still serialized as a value
+        let event = Contract::event_of_type::<Transfer>(&provider).address(tokens[0]);
+        let json = serde_json::to_value(event.filter).unwrap();
+        assert_eq!(json["address"], serde_json::to_value(tokens[0]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn simulation_surfaces_reverts_before_sending() {
+        use ethers_contract::{Contract, ContractError};
+        use ethers_core::abi::parse_abi;
+        use ethers_providers::JsonRpcError;
+
+        let (provider, mock) = Provider::mocked();
+        let abi = parse_abi(&["function withdraw(uint256 amount) returns (bool)"]).unwrap();
+        let contract = Contract::new(Address::zero(), abi, provider);
+        let call = contract.method::<_, bool>("withdraw", U256::from(1u64)).unwrap();
+
+        let output = Bytes::from(encode(&[Token::Bool(true)]));
+        mock.push::<Bytes, _>(output.clone()).unwrap();
+        assert_eq!(call.simulate().await.unwrap(), output);
+
+        // the revert is reported before anything is signed or sent
+        let data: Bytes = "0x08c379a0".parse().unwrap();
+        mock.push_error(JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: Some(serde_json::json!(data)),
+        });
+        match call.send_with_simulation().await.unwrap_err() {
+            ContractError::Reverted(revert) => assert_eq!(revert.data, Some(data)),
+            err => panic!("expected a revert, got {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn log_meta_matches_the_raw_log() {
+        use ethers_contract::Contract;
+        use ethers_core::types::{Log, Transaction};
+
+        #[derive(Clone, Debug, PartialEq, EthEvent)]
+        struct Transfer {
+            #[ethevent(indexed)]
+            from: Address,
+            #[ethevent(indexed)]
+            to: Address,
+            value: U256,
+        }
+
+        let (provider, mock) = Provider::mocked();
+        let token = Address::random();
+        let (from, to) = (Address::random(), Address::random());
+        let log = Log {
+            address: token,
+            topics: vec![Transfer::signature(), from.into(), to.into()],
+            data: encode(&[Token::Uint(7u64.into())]).into(),
+            block_hash: Some(H256::random()),
+            block_number: Some(12u64.into()),
+            transaction_hash: Some(H256::random()),
+            transaction_index: Some(3u64.into()),
+            log_index: Some(5u64.into()),
+            ..Default::default()
+        };
+        let tx = Transaction { hash: log.transaction_hash.unwrap(), ..Default::default() };
+        mock.push(tx.clone()).unwrap();
+        mock.push::<Vec<Log>, _>(vec![log.clone()]).unwrap();
+
+        let contract = Contract::new(token, ethers_core::abi::Abi::default(), provider.clone());
+        let events = contract.event::<Transfer>().query_with_meta().await.unwrap();
+        let (event, meta) = &events[0];
+        assert_eq!(*event, Transfer { from, to, value: 7u64.into() });
+        assert_eq!(meta.address, log.address);
+        assert_eq!(Some(meta.block_hash), log.block_hash);
+        assert_eq!(Some(meta.block_number), log.block_number);
+        assert_eq!(Some(meta.transaction_hash), log.transaction_hash);
+        assert_eq!(Some(meta.transaction_index), log.transaction_index);
+        assert_eq!(Some(meta.log_index), log.log_index);
+
+        assert_eq!(meta.transaction(&provider).await.unwrap(), Some(tx));
+    }
+
+    #[tokio::test]
+    async fn deployment_is_validated_before_sending() {
+        use ethers_contract::ContractError;
+        use ethers_core::abi::parse_abi;
+        use ethers_providers::JsonRpcError;
+
+        let (provider, mock) = Provider::mocked();
+        let client = Arc::new(provider);
+        let abi = parse_abi(&["constructor(uint256 supply)"]).unwrap();
+        let bytecode = Bytes::from(vec![0x60, 0x80, 0x60, 0x40]);
+
+        // a reverting constructor is caught by the simulation
+        mock.push_error(JsonRpcError {
+            code: 3,
+            message: "execution reverted".to_string(),
+            data: Some(serde_json::json!("0x")),
+        });
+        let factory = ContractFactory::new(abi.clone(), bytecode, client.clone());
+        let err = factory.deploy(U256::zero()).unwrap().send().await.unwrap_err();
+        assert!(matches!(err, ContractError::Reverted(_)), "{:?}", err);
+
+        // nothing to deploy
+        let factory = ContractFactory::new(Default::default(), Bytes::default(), client.clone());
+        let deployer = factory.deploy(()).unwrap();
+        assert!(matches!(deployer.validate().await, Err(ContractError::EmptyBytecode)));
+        // constructor arguments alone are no bytecode either
+        let factory = ContractFactory::new(abi, Bytes::default(), client);
+        let with_args = factory.deploy(U256::one()).unwrap();
+        assert!(with_args.tx.data().map_or(false, |data| !data.is_empty()));
+        assert!(matches!(with_args.validate().await, Err(ContractError::EmptyBytecode)));
+        // without validation the deployment is sent, which fails as the mock has no response
+        let err = deployer.skip_validation().send().await.unwrap_err();
+        assert!(matches!(err, ContractError::MiddlewareError(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn verifies_deployed_code_and_proxy_implementation() {
+        use ethers_contract::{Contract, EIP1967_IMPLEMENTATION_SLOT};
+
+        let (provider, mock) = Provider::mocked();
+        let address = Address::repeat_byte(0x11);
+        let contract = Contract::new(address, ethers_core::abi::Abi::default(), provider);
+        let code = Bytes::from(vec![0x60, 0x80, 0x60, 0x40]);
+
+        mock.push::<Bytes, _>(code.clone()).unwrap();
+        assert_eq!(contract.deployed_code_hash().await.unwrap(), H256::from(keccak256(&code)));
+        mock.assert_request("eth_getCode", (address, "latest")).unwrap();
+
+        mock.push::<Bytes, _>(code.clone()).unwrap();
+        assert!(contract.verify_deployed_code(&code).await.unwrap());
+        mock.push::<Bytes, _>(code.clone()).unwrap();
+        assert!(!contract.verify_deployed_code(&code[1..]).await.unwrap());
+
+        let slot = U256::from(keccak256("eip1967.proxy.implementation")) - 1;
+        assert_eq!(EIP1967_IMPLEMENTATION_SLOT, H256::from(<[u8; 32]>::from(slot)));
+
+        let implementation = Address::repeat_byte(0x22);
+        mock.push(H256::from(implementation)).unwrap();
+        assert_eq!(contract.implementation().await.unwrap(), implementation);
+    }
+
+    #[tokio::test]
+    async fn queries_all_events_of_a_contract() {
+        use ethers_contract::Contract;
+        use ethers_core::{abi::parse_abi, types::Log};
+
+        let (provider, mock) = Provider::mocked();
+        let address = Address::repeat_byte(0x11);
+        let abi = parse_abi(&[
+            "event ValueChanged(address indexed author, string newValue)",
+            "event Paused(bool paused)",
+        ])
+        .unwrap();
+        let contract = Contract::new(address, abi.clone(), provider);
+
+        let value_changed = Log {
+            address,
+            topics: vec![
+                abi.event("ValueChanged").unwrap().signature(),
+                Address::repeat_byte(0x22).into(),
+            ],
+            data: encode(&[Token::String("hi".to_string())]).into(),
+            ..Default::default()
+        };
+        let paused = Log {
+            address,
+            topics: vec![abi.event("Paused").unwrap().signature()],
+            data: encode(&[Token::Bool(true)]).into(),
+            ..Default::default()
+        };
+        mock.push::<Vec<Log>, _>(vec![value_changed, paused]).unwrap();
+
+        let events = contract.query_all_events(Filter::new().from_block(1u64)).await.unwrap();
+        assert_eq!(
+            events,
+            vec![
+                (
+                    "ValueChanged".to_string(),
+                    vec![
+                        Token::Address(Address::repeat_byte(0x22)),
+                        Token::String("hi".to_string())
+                    ]
+                ),
+                ("Paused".to_string(), vec![Token::Bool(true)]),
+            ]
+        );
+        let filter = Filter::new().from_block(1u64).address(address);
+        mock.assert_request("eth_getLogs", [filter]).unwrap();
+    }
+
+    #[tokio::test]
+    async fn watches_all_events_of_a_contract() {
+        use ethers_contract::Contract;
+        use ethers_core::{abi::parse_abi, types::Log};
+
+        let (provider, mock) = Provider::mocked();
+        let provider = provider.interval(Duration::from_millis(10));
+        let address = Address::repeat_byte(0x11);
+        let abi = parse_abi(&["event Paused(bool paused)", "event Unpaused()"]).unwrap();
+        let contract = Contract::new(address, abi.clone(), provider);
+
+        let paused = Log {
+            address,
+            topics: vec![abi.event("Paused").unwrap().signature()],
+            data: encode(&[Token::Bool(true)]).into(),
+            ..Default::default()
+        };
+        let unpaused = Log {
+            address,
+            topics: vec![abi.event("Unpaused").unwrap().signature()],
+            ..Default::default()
+        };
+        mock.push::<Vec<Log>, _>(vec![paused, unpaused]).unwrap();
+        mock.push(U256::from(1u64)).unwrap();
+
+        let stream = contract.all_events().await.unwrap();
+        let events: Vec<_> = stream.take(2).map(Result::unwrap).collect().await;
+        assert_eq!(
+            events,
+            vec![("Paused".to_string(), vec![Token::Bool(true)]), ("Unpaused".to_string(), vec![]),]
+        );
+        mock.assert_request("eth_newFilter", [Filter::new().address(address)]).unwrap();
+    }
 }
@@ -598,6 +598,8 @@ impl<'input> HumanReadableParser<'input> {
         }
         loop {
             params.push(self.take_param()?);
+            // components of inline tuples may be named, e.g. `(address owner, uint256 amount)`
+            let _name = self.take_name_opt()?;
 
             let (l, next, r) = match self.peek() {
                 Some(next) => next?,
@@ -210,8 +210,7 @@ impl AbiParser {
                     let inputs = if event.is_empty() {
                         Vec::new()
                     } else {
-                        event
-                            .split(',')
+                        split_top_level(event)
                             .map(|e| self.parse_event_arg(e))
                             .collect::<Result<Vec<_>, _>>()?
                             .into_iter()
@@ -241,6 +240,16 @@ impl AbiParser {
     ///
     /// See [`Self::parse_type`]
     fn parse_event_arg(&self, input: &str) -> Result<(EventParam, Option<String>)> {
+        if let Some((type_str, rest)) = split_tuple_type(input) {
+            let (indexed, name) = match rest.split_once(is_whitespace) {
+                Some(("indexed", name)) => (true, name.trim()),
+                _ if rest == "indexed" => (true, ""),
+                _ => (false, rest),
+            };
+            let (kind, user_ty) = self.parse_type(type_str)?;
+            return Ok((EventParam { name: name.to_string(), indexed, kind }, user_ty))
+        }
+
         let mut iter = input.trim().rsplitn(3, is_whitespace);
         let mut indexed = false;
         let mut name =
@@ -292,31 +301,17 @@ impl AbiParser {
             .strip_prefix('(')
             .ok_or_else(|| format_err!("Expected input args parentheses at `{}`", s))?;
 
-        let (input_args_modifiers, output_args) = match input.rsplit_once('(') {
-            Some((first, second)) => (first, Some(second)),
-            None => (input, None),
-        };
+        let (input_args, rest) = split_at_closing_parenthesis(input)
+            .ok_or_else(|| format_err!("Expected input args parentheses at `{}`", s))?;
+        let input_args = if input_args.is_empty() { None } else { Some(input_args) };
 
-        let mut input_args_modifiers_iter = input_args_modifiers
-            .trim_end()
-            .strip_suffix(" returns")
-            .unwrap_or(input_args_modifiers)
-            .splitn(2, ')');
-
-        let input_args = match input_args_modifiers_iter
-            .next()
-            .ok_or_else(|| format_err!("Expected input args parentheses at `{}`", s))?
-        {
-            "" => None,
-            input_params_args => Some(input_params_args),
-        };
-        let modifiers = match input_args_modifiers_iter
-            .next()
-            .ok_or_else(|| format_err!("Expected input args parentheses at `{}`", s))?
-        {
-            "" => None,
-            modifiers => Some(modifiers),
+        let (modifiers, output_args) = match rest.split_once('(') {
+            Some((modifiers, outputs)) => {
+                (modifiers.trim_end().strip_suffix("returns").unwrap_or(modifiers), Some(outputs))
+            }
+            None => (rest, None),
         };
+        let modifiers = if modifiers.is_empty() { None } else { Some(modifiers) };
 
         let inputs = if let Some(params) = input_args {
             self.parse_params(params)?
@@ -335,10 +330,10 @@ impl AbiParser {
         };
 
         let outputs = if let Some(params) = output_args {
-            let params = params
-                .trim()
-                .strip_suffix(')')
-                .ok_or_else(|| format_err!("Expected output args parentheses at `{}`", s))?;
+            let params = match split_at_closing_parenthesis(params) {
+                Some((params, rest)) if rest.trim().is_empty() => params,
+                _ => bail!("Expected output args parentheses at `{}`", s),
+            };
             let output_params = self.parse_params(params)?;
             let mut outputs = Vec::with_capacity(output_params.len());
             let mut output_types = Vec::new();
@@ -365,7 +360,7 @@ impl AbiParser {
     }
 
     fn parse_params(&self, s: &str) -> Result<Vec<(Param, Option<String>)>> {
-        s.split(',')
+        split_top_level(s)
             .filter(|s| !s.is_empty())
             .map(|s| self.parse_param(s))
             .collect::<Result<Vec<_>, _>>()
@@ -462,6 +457,16 @@ impl AbiParser {
     }
 
     fn parse_param(&self, param: &str) -> Result<(Param, Option<String>)> {
+        if let Some((type_str, rest)) = split_tuple_type(param) {
+            let name = match rest.rsplit_once(is_whitespace) {
+                Some((_location, name)) => name,
+                None if rest == "memory" || rest == "calldata" => "",
+                None => rest,
+            };
+            let (kind, user_struct) = self.parse_type(type_str)?;
+            return Ok((Param { name: name.to_string(), kind, internal_type: None }, user_struct))
+        }
+
         let mut iter = param.trim().rsplitn(3, is_whitespace);
 
         let mut name =
@@ -556,6 +561,45 @@ pub(crate) fn is_whitespace(c: char) -> bool {
     matches!(c, ' ' | '\t')
 }
 
+/// Splits `input` at the `)` that closes an already consumed `(`, returning the content of the
+/// parentheses and the remainder after the closing `)`
+fn split_at_closing_parenthesis(input: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    for (idx, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some((&input[..idx], &input[idx + 1..])),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits a list of params at all commas that are not part of an inline tuple
+fn split_top_level(input: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    input.split(move |c| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' => return depth == 0,
+            _ => {}
+        }
+        false
+    })
+}
+
+/// If the param starts with an inline tuple type like `(address,uint256)[]`, returns the type and
+/// the trimmed remainder, i.e. the location and name of the param
+fn split_tuple_type(param: &str) -> Option<(&str, &str)> {
+    let param = param.trim();
+    let (_, rest) = split_at_closing_parenthesis(param.strip_prefix('(')?)?;
+    // array suffixes belong to the type
+    let end = param.len() - rest.len() + rest.find(is_whitespace).unwrap_or(rest.len());
+    Some((&param[..end], param[end..].trim()))
+}
+
 fn escape_quotes(input: &str) -> &str {
     input.trim_matches(is_whitespace).trim_matches('\"')
 }
@@ -848,4 +892,70 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parses_inline_tuple_params() {
+        let fn_str = "function foo((address owner, uint256 amount) pair)";
+        let parsed = AbiParser::default().parse_function(fn_str).unwrap();
+        assert_eq!(parsed.name, "foo");
+        assert_eq!(parsed.inputs.len(), 1);
+        assert_eq!(parsed.inputs[0].name, "pair");
+        assert_eq!(
+            parsed.inputs[0].kind,
+            ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)])
+        );
+
+        let fn_str = "function bar((uint256,(bool,address))[] memory items, uint8 x) returns ((bool,bytes)[] results)";
+        let parsed = AbiParser::default().parse_function(fn_str).unwrap();
+        assert_eq!(parsed.inputs[0].name, "items");
+        assert_eq!(
+            parsed.inputs[0].kind,
+            ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Uint(256),
+                ParamType::Tuple(vec![ParamType::Bool, ParamType::Address])
+            ])))
+        );
+        assert_eq!(parsed.inputs[1].kind, ParamType::Uint(8));
+        assert_eq!(parsed.outputs[0].name, "results");
+        assert_eq!(
+            parsed.outputs[0].kind,
+            ParamType::Array(Box::new(ParamType::Tuple(vec![ParamType::Bool, ParamType::Bytes])))
+        );
+
+        let event = AbiParser::default()
+            .parse_event("event Swap((address,uint256) indexed pair, (bool,bool) flags)")
+            .unwrap();
+        assert!(event.inputs[0].indexed);
+        assert_eq!(event.inputs[0].name, "pair");
+        assert!(!event.inputs[1].indexed);
+        assert_eq!(event.inputs[1].kind, ParamType::Tuple(vec![ParamType::Bool, ParamType::Bool]));
+    }
+
+    #[test]
+    fn parses_uniswap_v3_exact_input_single() {
+        let abi = parse(&[
+            "function exactInputSingle((address tokenIn, address tokenOut, uint24 fee, address recipient, uint256 deadline, uint256 amountIn, uint256 amountOutMinimum, uint160 sqrtPriceLimitX96) params) external payable returns (uint256 amountOut)",
+        ])
+        .unwrap();
+        let function = abi.function("exactInputSingle").unwrap();
+        assert_eq!(function.state_mutability, StateMutability::Payable);
+        assert_eq!(function.inputs[0].name, "params");
+        assert_eq!(
+            function.inputs[0].kind,
+            ParamType::Tuple(vec![
+                ParamType::Address,
+                ParamType::Address,
+                ParamType::Uint(24),
+                ParamType::Address,
+                ParamType::Uint(256),
+                ParamType::Uint(256),
+                ParamType::Uint(256),
+                ParamType::Uint(160),
+            ])
+        );
+        assert_eq!(function.outputs[0].name, "amountOut");
+        assert_eq!(function.outputs[0].kind, ParamType::Uint(256));
+        // the selector of `ISwapRouter.exactInputSingle`
+        assert_eq!(function.short_signature(), [0x41, 0x4b, 0xf3, 0x89]);
+    }
 }
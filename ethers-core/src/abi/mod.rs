@@ -44,6 +44,21 @@ pub trait FunctionExt: sealed::Sealed {
 
     /// Compute the Keccak256 function selector used by contract ABIs.
     fn selector(&self) -> Selector;
+
+    /// Returns the human-readable Solidity declaration of the function, including parameter
+    /// names, state mutability and outputs, e.g.
+    /// `function transfer(address to, uint256 amount) external returns (bool success)`
+    fn full_signature(&self) -> String;
+
+    /// ABI-encodes the given arguments, prefixed with the function's selector.
+    fn encode_call(&self, args: &[Token]) -> std::result::Result<Bytes, AbiError>;
+
+    /// Decodes the arguments of a call to this function from raw call data, e.g. the `input` of a
+    /// transaction.
+    ///
+    /// Unlike [`Function::decode_input`], this expects `data` to start with the function's
+    /// selector and fails with [`AbiError::WrongSelector`] if it does not.
+    fn decode_call(&self, data: &[u8]) -> std::result::Result<Vec<Token>, AbiError>;
 }
 
 impl FunctionExt for Function {
@@ -59,6 +74,43 @@ impl FunctionExt for Function {
     fn selector(&self) -> Selector {
         id(self.abi_signature())
     }
+
+    fn full_signature(&self) -> String {
+        let mut signature =
+            format!("function {}({}) external", self.name, format_params(&self.inputs));
+        match self.state_mutability {
+            StateMutability::Pure => signature.push_str(" pure"),
+            StateMutability::View => signature.push_str(" view"),
+            StateMutability::Payable => signature.push_str(" payable"),
+            StateMutability::NonPayable => {}
+        }
+        if !self.outputs.is_empty() {
+            signature.push_str(&format!(" returns ({})", format_params(&self.outputs)));
+        }
+        signature
+    }
+
+    fn encode_call(&self, args: &[Token]) -> std::result::Result<Bytes, AbiError> {
+        Ok(self.encode_input(args)?.into())
+    }
+
+    fn decode_call(&self, data: &[u8]) -> std::result::Result<Vec<Token>, AbiError> {
+        if data.len() < 4 || data[..4] != self.selector() {
+            return Err(AbiError::WrongSelector)
+        }
+        Ok(self.decode_input(&data[4..])?)
+    }
+}
+
+/// A call to a contract function decoded from raw call data.
+///
+/// See [`TransactionRequest::decode_calldata`](crate::types::TransactionRequest::decode_calldata)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCall {
+    /// The called function
+    pub function: Function,
+    /// The decoded arguments of the call
+    pub args: Vec<Token>,
 }
 
 /// Extension trait for `ethabi::Event`.
@@ -67,6 +119,28 @@ pub trait EventExt: sealed::Sealed {
     /// hash of this value is the actual event signature that is used as topic0
     /// in the transaction logs.
     fn abi_signature(&self) -> String;
+
+    /// Returns the human-readable Solidity declaration of the event, including parameter names
+    /// and indexed markers, e.g.
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)`
+    ///
+    /// The event's topic0 is [`Event::signature`].
+    fn full_signature(&self) -> String;
+
+    /// Decodes the indexed parameters of the event from the topics of a log.
+    ///
+    /// Unless the event is anonymous, the first topic must be the event's signature. Indexed
+    /// parameters of dynamic types (strings, bytes, arrays and tuples) are only available as
+    /// the keccak256 hash of their value and are decoded as `FixedBytes(32)`.
+    fn decode_topics(&self, topics: &[H256]) -> std::result::Result<PartialEvent, AbiError>;
+
+    /// Decodes the non-indexed parameters of the event from the data of a log.
+    fn decode_data(&self, data: &[u8]) -> std::result::Result<PartialEvent, AbiError>;
+
+    /// Decodes all parameters of the event from a log, in the order they are declared in.
+    ///
+    /// This is the merge of [`EventExt::decode_topics`] and [`EventExt::decode_data`].
+    fn decode_log(&self, log: &RawLog) -> std::result::Result<Vec<Token>, AbiError>;
 }
 
 impl EventExt for Event {
@@ -78,6 +152,128 @@ impl EventExt for Event {
             if self.anonymous { " anonymous" } else { "" },
         )
     }
+
+    fn full_signature(&self) -> String {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| {
+                let indexed = if input.indexed { " indexed" } else { "" };
+                format_param(&input.kind, indexed, &input.name)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("event {}({}){}", self.name, inputs, if self.anonymous { " anonymous" } else { "" })
+    }
+
+    fn decode_topics(&self, topics: &[H256]) -> std::result::Result<PartialEvent, AbiError> {
+        let topics = if self.anonymous {
+            topics
+        } else {
+            match topics.split_first() {
+                Some((signature, topics)) if *signature == self.signature() => topics,
+                _ => return Err(Error::InvalidData.into()),
+            }
+        };
+
+        let indexed = self.inputs.iter().filter(|input| input.indexed);
+        if indexed.clone().count() != topics.len() {
+            return Err(Error::InvalidData.into())
+        }
+        let types: Vec<_> = indexed
+            .map(|input| match input.kind {
+                ParamType::String |
+                ParamType::Bytes |
+                ParamType::Array(_) |
+                ParamType::FixedArray(_, _) |
+                ParamType::Tuple(_) => ParamType::FixedBytes(32),
+                ref kind => kind.clone(),
+            })
+            .collect();
+        let data: Vec<u8> = topics.iter().flat_map(|topic| topic.as_bytes().to_vec()).collect();
+        let tokens = decode(&types, &data)?;
+
+        Ok(PartialEvent::new(self, true, tokens))
+    }
+
+    fn decode_data(&self, data: &[u8]) -> std::result::Result<PartialEvent, AbiError> {
+        let types: Vec<_> = self
+            .inputs
+            .iter()
+            .filter(|input| !input.indexed)
+            .map(|input| input.kind.clone())
+            .collect();
+        let tokens = decode(&types, data)?;
+
+        Ok(PartialEvent::new(self, false, tokens))
+    }
+
+    fn decode_log(&self, log: &RawLog) -> std::result::Result<Vec<Token>, AbiError> {
+        let event = self.decode_topics(&log.topics)?.merge(self.decode_data(&log.data)?);
+        event.into_tokens().ok_or_else(|| Error::InvalidData.into())
+    }
+}
+
+/// The parameters of an event decoded from either the topics or the data of a log.
+///
+/// See [`EventExt::decode_topics`] and [`EventExt::decode_data`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartialEvent {
+    /// The decoded parameters, in the order they are declared in the event, or `None` for the
+    /// parameters that were not decoded
+    pub params: Vec<Option<Token>>,
+}
+
+impl PartialEvent {
+    /// Assigns `tokens` to the indexed or non-indexed parameters of `event`
+    fn new(event: &Event, indexed: bool, tokens: Vec<Token>) -> Self {
+        let mut tokens = tokens.into_iter();
+        let params = event
+            .inputs
+            .iter()
+            .map(|input| if input.indexed == indexed { tokens.next() } else { None })
+            .collect();
+        Self { params }
+    }
+
+    /// Combines the parameters decoded from the topics with the ones decoded from the data (or
+    /// vice versa). Parameters decoded in both are taken from `self`.
+    #[must_use]
+    pub fn merge(self, other: PartialEvent) -> Self {
+        let mut params = self.params;
+        if params.len() < other.params.len() {
+            params.resize(other.params.len(), None);
+        }
+        for (param, other) in params.iter_mut().zip(other.params) {
+            if param.is_none() {
+                *param = other;
+            }
+        }
+        Self { params }
+    }
+
+    /// Returns true if all parameters were decoded
+    pub fn is_complete(&self) -> bool {
+        self.params.iter().all(Option::is_some)
+    }
+
+    /// Returns all parameters, or `None` if some of them were not decoded
+    pub fn into_tokens(self) -> Option<Vec<Token>> {
+        self.params.into_iter().collect()
+    }
+}
+
+/// Formats the params as comma separated `type name` pairs
+fn format_params(params: &[Param]) -> String {
+    params.iter().map(|p| format_param(&p.kind, "", &p.name)).collect::<Vec<_>>().join(", ")
+}
+
+fn format_param(kind: &ParamType, modifier: &str, name: &str) -> String {
+    if name.is_empty() {
+        format!("{kind}{modifier}")
+    } else {
+        format!("{kind}{modifier} {name}")
+    }
 }
 
 /// Extension trait for `ethabi::AbiError`.
@@ -267,6 +463,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn encode_decode_call() {
+        let abi =
+            parse_abi(&["function transfer(address to, uint256 amount) returns (bool)"]).unwrap();
+        let function = abi.function("transfer").unwrap();
+        let args = vec![Token::Address(Address::repeat_byte(0x11)), Token::Uint(100u64.into())];
+
+        let data = function.encode_call(&args).unwrap();
+        assert_eq!(data[..4], function.selector());
+        assert_eq!(function.decode_call(&data).unwrap(), args);
+
+        assert!(matches!(function.decode_call(&data[..3]), Err(AbiError::WrongSelector)));
+        assert!(matches!(function.decode_call(&data[4..]), Err(AbiError::WrongSelector)));
+
+        let output = crate::abi::encode(&[Token::Bool(true)]);
+        assert_eq!(function.decode_output(&output).unwrap(), vec![Token::Bool(true)]);
+    }
+
+    #[test]
+    fn full_signatures() {
+        let abi = parse_abi(&[
+            "function transfer(address to, uint256 amount) external returns (bool success)",
+            "function balanceOf(address) external view returns (uint256)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+
+        let transfer = abi.function("transfer").unwrap();
+        assert_eq!(
+            transfer.full_signature(),
+            "function transfer(address to, uint256 amount) external returns (bool success)"
+        );
+        let reparsed = parse_abi(&[&transfer.full_signature()]).unwrap();
+        assert_eq!(reparsed.function("transfer").unwrap(), transfer);
+
+        assert_eq!(
+            abi.function("balanceOf").unwrap().full_signature(),
+            "function balanceOf(address) external view returns (uint256)"
+        );
+
+        let event = abi.event("Transfer").unwrap();
+        assert_eq!(
+            event.full_signature(),
+            "event Transfer(address indexed from, address indexed to, uint256 value)"
+        );
+        assert_eq!(
+            event.signature(),
+            crate::utils::keccak256("Transfer(address,address,uint256)").into()
+        );
+    }
+
     #[test]
     fn format_event_signature() {
         for (e, expected) in &[
@@ -287,6 +534,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_topics_and_data_separately() {
+        let abi = parse_abi(&[
+            "event Transfer(address indexed from, uint256 value, address indexed to, string memo)",
+        ])
+        .unwrap();
+        let event = abi.event("Transfer").unwrap();
+        let (from, to) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+        let log = RawLog {
+            topics: vec![event.signature(), H256::from(from), H256::from(to)],
+            data: encode(&[Token::Uint(100u64.into()), Token::String("gm".to_string())]),
+        };
+
+        let topics = event.decode_topics(&log.topics).unwrap();
+        assert_eq!(
+            topics.params,
+            vec![Some(Token::Address(from)), None, Some(Token::Address(to)), None]
+        );
+        assert!(!topics.is_complete());
+
+        let data = event.decode_data(&log.data).unwrap();
+        assert_eq!(
+            data.params,
+            vec![
+                None,
+                Some(Token::Uint(100u64.into())),
+                None,
+                Some(Token::String("gm".to_string()))
+            ]
+        );
+
+        let tokens = topics.merge(data).into_tokens().unwrap();
+        assert_eq!(tokens, event.decode_log(&log).unwrap());
+        let parsed = event.parse_log(log.clone()).unwrap();
+        assert_eq!(tokens, parsed.params.into_iter().map(|p| p.value).collect::<Vec<_>>());
+
+        // wrong signature and missing topics
+        assert!(event.decode_topics(&log.topics[1..]).is_err());
+        assert!(event.decode_topics(&log.topics[..2]).is_err());
+    }
+
     #[test]
     fn abi_type_works() {
         assert_eq!(ParamType::Bytes, Bytes::param_type());
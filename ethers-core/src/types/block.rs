@@ -1,5 +1,7 @@
 // Taken from <https://github.com/tomusdrw/rust-web3/blob/master/src/types/block.rs>
 use crate::types::{Address, Bloom, Bytes, Transaction, TxHash, H256, U256, U64};
+#[cfg(not(feature = "celo"))]
+use crate::types::{Withdrawal, H64};
 use chrono::{DateTime, TimeZone, Utc};
 #[cfg(not(feature = "celo"))]
 use core::cmp::Ordering;
@@ -68,10 +70,14 @@ pub struct Block<TX> {
     pub seal_fields: Vec<Bytes>,
     /// Uncles' hashes
     #[cfg(not(feature = "celo"))]
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     pub uncles: Vec<H256>,
-    /// Transactions
-    #[serde(bound = "TX: Serialize + serde::de::DeserializeOwned", default)]
+    /// Transactions, empty if the node omitted them
+    #[serde(
+        bound = "TX: Serialize + serde::de::DeserializeOwned",
+        default,
+        deserialize_with = "deserialize_null_default"
+    )]
     pub transactions: Vec<TX>,
     /// Size in bytes
     pub size: Option<U256>,
@@ -85,6 +91,26 @@ pub struct Block<TX> {
     /// Base fee per unit of gas (if past London)
     #[serde(rename = "baseFeePerGas")]
     pub base_fee_per_gas: Option<U256>,
+    /// Withdrawals root hash (if past Shanghai)
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "withdrawalsRoot")]
+    #[cfg(not(feature = "celo"))]
+    pub withdrawals_root: Option<H256>,
+    /// Withdrawals (if past Shanghai)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "celo"))]
+    pub withdrawals: Option<Vec<Withdrawal>>,
+    /// Total blob gas used by the block's transactions (if past Cancun)
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "blobGasUsed")]
+    #[cfg(not(feature = "celo"))]
+    pub blob_gas_used: Option<U64>,
+    /// Running total of blob gas in excess of the target (if past Cancun)
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "excessBlobGas")]
+    #[cfg(not(feature = "celo"))]
+    pub excess_blob_gas: Option<U64>,
+    /// Root of the parent beacon block (if past Cancun)
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "parentBeaconBlockRoot")]
+    #[cfg(not(feature = "celo"))]
+    pub parent_beacon_block_root: Option<H256>,
 
     #[cfg(feature = "celo")]
     #[cfg_attr(docsrs, doc(cfg(feature = "celo")))]
@@ -124,6 +150,14 @@ pub enum TimeError {
     TimestampOverflow,
 }
 
+/// Error returned by [`Block::unwrap_number`].
+#[derive(Clone, Copy, Debug, Error)]
+pub enum BlockError {
+    /// The block is still pending and has no number yet.
+    #[error("block is pending")]
+    PendingBlock,
+}
+
 // ref <https://eips.ethereum.org/EIPS/eip-1559>
 #[cfg(not(feature = "celo"))]
 pub const ELASTICITY_MULTIPLIER: U256 = U256([2u64, 0, 0, 0]);
@@ -169,6 +203,40 @@ impl<TX> Block<TX> {
         }
     }
 
+    /// Returns true if the block was produced after the Merge, i.e. by proof of stake.
+    ///
+    /// Post-Merge blocks have a zero difficulty and a zero nonce, see
+    /// [EIP-3675](https://eips.ethereum.org/EIPS/eip-3675).
+    #[cfg(not(feature = "celo"))]
+    pub fn is_post_merge(&self) -> bool {
+        self.difficulty.is_zero() && self.nonce == Some(H64::zero())
+    }
+
+    /// Returns true if this is a pending block, i.e. the block returned for the `pending` tag.
+    ///
+    /// Pending blocks are not sealed yet and therefore have no hash. Depending on the client
+    /// the number, nonce and miner are missing as well.
+    pub fn is_pending(&self) -> bool {
+        self.hash.is_none()
+    }
+
+    /// Returns true if the block only holds the fields of the block header, like the blocks of a
+    /// `newHeads` subscription.
+    ///
+    /// Header-only blocks have no size and no transactions.
+    pub fn is_header_only(&self) -> bool {
+        self.size.is_none() && self.transactions.is_empty()
+    }
+
+    /// Returns the block's number.
+    ///
+    /// # Errors
+    ///
+    /// * [`BlockError::PendingBlock`] if the block is pending and has no number.
+    pub fn unwrap_number(&self) -> Result<U64, BlockError> {
+        self.number.ok_or(BlockError::PendingBlock)
+    }
+
     /// Parse [`Self::timestamp`] into a [`DateTime<Utc>`].
     ///
     /// # Errors
@@ -217,6 +285,11 @@ impl Block<TxHash> {
                 mix_hash,
                 nonce,
                 base_fee_per_gas,
+                withdrawals_root,
+                withdrawals,
+                blob_gas_used,
+                excess_blob_gas,
+                parent_beacon_block_root,
                 other,
                 ..
             } = self;
@@ -242,6 +315,11 @@ impl Block<TxHash> {
                 mix_hash,
                 nonce,
                 base_fee_per_gas,
+                withdrawals_root,
+                withdrawals,
+                blob_gas_used,
+                excess_blob_gas,
+                parent_beacon_block_root,
                 transactions,
                 other,
             }
@@ -321,6 +399,11 @@ impl From<Block<Transaction>> for Block<TxHash> {
                 mix_hash,
                 nonce,
                 base_fee_per_gas,
+                withdrawals_root,
+                withdrawals,
+                blob_gas_used,
+                excess_blob_gas,
+                parent_beacon_block_root,
                 other,
             } = full;
             Block {
@@ -345,6 +428,11 @@ impl From<Block<Transaction>> for Block<TxHash> {
                 mix_hash,
                 nonce,
                 base_fee_per_gas,
+                withdrawals_root,
+                withdrawals,
+                blob_gas_used,
+                excess_blob_gas,
+                parent_beacon_block_root,
                 transactions: transactions.iter().map(|tx| tx.hash).collect(),
                 other,
             }
@@ -763,6 +851,46 @@ mod tests {
         let _block: Block<TxHash> = serde_json::from_str(block).unwrap();
     }
 
+    #[test]
+    #[cfg(not(feature = "celo"))]
+    fn deserialize_new_heads() {
+        let header = r#"{"number":"0x3","hash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","parentHash":"0x689c70c080ca22bc0e681694fa803c1aba16a69c8b6368fed5311d279eb9de90","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","transactionsRoot":"0x7270c1c4440180f2bd5215809ee3d545df042b67329499e1ab97eb759d31610d","stateRoot":"0x29f32984517a7d25607da485b23cefabfd443751422ca7e603395e1de9bc8a4b","receiptsRoot":"0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2","miner":"0x0000000000000000000000000000000000000000","difficulty":"0x0","extraData":"0x","gasLimit":"0x6691b7","gasUsed":"0x5208","timestamp":"0x5ecedbb9","baseFeePerGas":"0x7"}"#;
+        let block: Block<TxHash> = serde_json::from_str(header).unwrap();
+        assert!(block.is_header_only());
+        assert_eq!(block.number, Some(3u64.into()));
+        assert_eq!(block.base_fee_per_gas, Some(7u64.into()));
+        assert_eq!(block.total_difficulty, None);
+
+        // a full block is not header only, even if it has no transactions
+        let block: Block<TxHash> = serde_json::from_value(serde_json::json!({
+            "hash": block.hash,
+            "size": "0x3e8",
+            "transactions": [],
+            "uncles": []
+        }))
+        .unwrap();
+        assert!(!block.is_header_only());
+    }
+
+    #[test]
+    #[cfg(not(feature = "celo"))]
+    fn deserialize_blk_null_fields() {
+        let block = serde_json::json!({
+            "number": "0x3",
+            "hash": "0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972",
+            "size": "0x3e8",
+            "nonce": null,
+            "mixHash": null,
+            "transactions": null,
+            "uncles": null
+        });
+        let block: Block<Transaction> = serde_json::from_value(block).unwrap();
+        assert!(block.transactions.is_empty());
+        assert!(block.uncles.is_empty());
+        assert_eq!(block.nonce, None);
+        assert_eq!(block.mix_hash, None);
+    }
+
     #[test]
     fn deserialize_blk_with_txs() {
         let block = r#"{"number":"0x3","hash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","parentHash":"0x689c70c080ca22bc0e681694fa803c1aba16a69c8b6368fed5311d279eb9de90","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","transactionsRoot":"0x7270c1c4440180f2bd5215809ee3d545df042b67329499e1ab97eb759d31610d","stateRoot":"0x29f32984517a7d25607da485b23cefabfd443751422ca7e603395e1de9bc8a4b","receiptsRoot":"0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2","miner":"0x0000000000000000000000000000000000000000","difficulty":"0x0","totalDifficulty":"0x0","extraData":"0x","size":"0x3e8","gasLimit":"0x6691b7","gasUsed":"0x5208","timestamp":"0x5ecedbb9","transactions":[{"hash":"0xc3c5f700243de37ae986082fd2af88d2a7c2752a0c0f7b9d6ac47c729d45e067","nonce":"0x2","blockHash":"0xda53da08ef6a3cbde84c33e51c04f68c3853b6a3731f10baa2324968eee63972","blockNumber":"0x3","transactionIndex":"0x0","from":"0xfdcedc3bfca10ecb0890337fbdd1977aba84807a","to":"0xdca8ce283150ab773bcbeb8d38289bdb5661de1e","value":"0x0","gas":"0x15f90","gasPrice":"0x4a817c800","input":"0x","v":"0x25","r":"0x19f2694eb9113656dbea0b925e2e7ceb43df83e601c4116aee9c0dd99130be88","s":"0x73e5764b324a4f7679d890a198ba658ba1c8cd36983ff9797e10b1b89dbb448e"}],"uncles":[]}"#;
@@ -869,6 +997,9 @@ mod tests {
         );
         let block: Block<H256> = serde_json::from_value(json).unwrap();
         assert!(block.author.is_none());
+        assert!(block.is_pending());
+        // geth already assigns the number to its pending block
+        assert_eq!(block.unwrap_number().unwrap(), U64::from(0xe1a6eeu64));
     }
 
     #[test]
@@ -897,7 +1028,118 @@ mod tests {
           "uncles": []
         }
               );
-        let _block: Block<TxHash> = serde_json::from_value(json).unwrap();
+        let block: Block<TxHash> = serde_json::from_value(json).unwrap();
+        assert!(!block.is_post_merge());
+    }
+
+    #[test]
+    fn post_cancun_block() {
+        let json = serde_json::json!({
+          "baseFeePerGas": "0x3df7aa60e",
+          "blobGasUsed": "0x40000",
+          "difficulty": "0x0",
+          "excessBlobGas": "0x1e0000",
+          "extraData": "0x6265617665726275696c642e6f7267",
+          "gasLimit": "0x1c9c380",
+          "gasUsed": "0x1312d00",
+          "hash": "0x4ea7e9e3235c86c8b1bd9d2a0f7c3bcd1ae0f1b8d2f6b1c8d5e0b7f64f2e1d3a",
+          "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+          "miner": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+          "mixHash": "0x1d4b3bcf1b7cb8a0e3e51d56f9d95f4bc8e3e8b1d99b3f0a1e6e1f2a3b4c5d6e",
+          "nonce": "0x0000000000000000",
+          "number": "0x1294a1b",
+          "parentBeaconBlockRoot": "0x3c8b99e2f1a6e2a2c3d0c8f3b1d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d2",
+          "parentHash": "0x7b9c1e6d1f0a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4",
+          "receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+          "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+          "size": "0x2f5",
+          "stateRoot": "0xa1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90",
+          "timestamp": "0x65f1b057",
+          "totalDifficulty": "0xc70d815d562d3cfa955",
+          "transactions": [],
+          "transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+          "uncles": [],
+          "withdrawals": [
+            {
+              "index": "0x2a6da2b",
+              "validatorIndex": "0xd1a3f",
+              "address": "0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f",
+              "amount": "0x11f5b2b"
+            }
+          ],
+          "withdrawalsRoot": "0x2914f3a1a3c0e4a5b0cf4f7a5e7bc1f6f9b9e3c9d4f5e6a7b8c9d0e1f2a3b4c5"
+        });
+
+        let block: Block<TxHash> = serde_json::from_value(json.clone()).unwrap();
+        assert!(block.is_post_merge());
+        assert_eq!(block.blob_gas_used, Some(0x40000u64.into()));
+        assert_eq!(block.excess_blob_gas, Some(0x1e0000u64.into()));
+        assert!(block.parent_beacon_block_root.is_some());
+        assert!(block.withdrawals_root.is_some());
+        assert_eq!(
+            block.withdrawals,
+            Some(vec![Withdrawal {
+                index: 0x2a6da2bu64.into(),
+                validator_index: 0xd1a3fu64.into(),
+                address: "0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f".parse().unwrap(),
+                amount: 0x11f5b2bu64.into(),
+            }])
+        );
+        // the post-Merge fields are not captured as unknown fields
+        assert!(block.other.is_empty());
+        let value = serde_json::to_value(&block).unwrap();
+        assert_eq!(value["withdrawals"], json["withdrawals"]);
+        assert_eq!(serde_json::from_value::<Block<TxHash>>(value).unwrap(), block);
+    }
+
+    #[test]
+    fn pending_block_without_number() {
+        // response of `eth_getBlockByNumber("pending", false)` without a number
+        let json = serde_json::json!({
+            "baseFeePerGas": "0x7",
+            "difficulty": "0x0",
+            "extraData": "0x",
+            "gasLimit": "0x1c9c380",
+            "gasUsed": "0x5208",
+            "hash": null,
+            "logsBloom": null,
+            "miner": null,
+            "mixHash": null,
+            "nonce": null,
+            "number": null,
+            "parentHash": "0x4e3a3754410177e6937ef1f84bba68ea139e8d1a2258c5f85db9f1cd715a1bdd",
+            "receiptsRoot": "0x056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2",
+            "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+            "size": "0x263",
+            "stateRoot": "0x1f8b6d690ee8b5fc405a8f6d33d9e8a1a0c0e76d1e2d8ba3f62f69a7b11c2d4e",
+            "timestamp": "0x6541e4d1",
+            "totalDifficulty": null,
+            "transactions": ["0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"],
+            "transactionsRoot": "0x8b6ecbea6a9ebdb6b05f8d7a0f6b429b2fce3bd5b438e5fd4e2e52bd28d4d1b7",
+            "uncles": []
+        });
+
+        let block: Block<TxHash> = serde_json::from_value(json).unwrap();
+        assert!(block.is_pending());
+        assert!(matches!(block.unwrap_number(), Err(BlockError::PendingBlock)));
+        assert_eq!(block.transactions.len(), 1);
+
+        let block = Block::<TxHash> {
+            hash: Some(H256::random()),
+            number: Some(15_000_000u64.into()),
+            ..Default::default()
+        };
+        assert!(!block.is_pending());
+        assert_eq!(block.unwrap_number().unwrap(), U64::from(15_000_000u64));
+    }
+
+    #[test]
+    fn pre_shanghai_block_omits_withdrawals() {
+        let block: Block<TxHash> = Block::default();
+        let json = serde_json::to_value(&block).unwrap();
+        assert!(json.get("withdrawals").is_none());
+        assert!(json.get("parentBeaconBlockRoot").is_none());
+        assert!(!block.is_post_merge());
     }
 }
 
@@ -0,0 +1,147 @@
+use crate::{
+    abi::{self, Abi, AbiError, Token},
+    types::{Bytes, Selector},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+
+/// The `DELEGATECALL` opcode
+const DELEGATECALL: u8 = 0xf4;
+/// The `PUSH1` opcode, followed by `PUSH2` through `PUSH32`
+const PUSH1: u8 = 0x60;
+const PUSH32: u8 = 0x7f;
+
+/// EVM bytecode, e.g. the deployed code of a contract as returned by `eth_getCode`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bytecode(pub Bytes);
+
+impl Bytecode {
+    /// Returns true if there is no code, i.e. the address is an externally owned account or does
+    /// not exist (yet)
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if the code contains a `DELEGATECALL` instruction.
+    ///
+    /// The data of `PUSH` instructions is skipped, bytes of the metadata appended by the compiler
+    /// are however treated as instructions.
+    pub fn contains_delegatecall(&self) -> bool {
+        let code = self.0.as_ref();
+        let mut pc = 0;
+        while let Some(&opcode) = code.get(pc) {
+            if opcode == DELEGATECALL {
+                return true
+            }
+            if (PUSH1..=PUSH32).contains(&opcode) {
+                pc += (opcode - PUSH1 + 1) as usize;
+            }
+            pc += 1;
+        }
+        false
+    }
+
+    /// Returns the 4 bytes at `offset`, e.g. a function selector pushed by a `PUSH4` in the
+    /// contract's dispatcher, or `None` if the code is too short
+    pub fn selector_at(&self, offset: usize) -> Option<Selector> {
+        let end = offset.checked_add(4)?;
+        self.0.get(offset..end).map(|bytes| bytes.try_into().expect("4 bytes; qed"))
+    }
+
+    /// Decodes the constructor arguments of a deployment.
+    ///
+    /// `self` is the creation bytecode of the contract and `init_code` the input of the
+    /// deployment transaction, i.e. the creation bytecode followed by the ABI encoded constructor
+    /// arguments.
+    pub fn decode_constructor(&self, abi: &Abi, init_code: &[u8]) -> Result<Vec<Token>, AbiError> {
+        let args = init_code.strip_prefix(self.0.as_ref()).ok_or(abi::Error::InvalidData)?;
+        let params: Vec<_> = abi
+            .constructor
+            .as_ref()
+            .map(|constructor| constructor.inputs.iter().map(|p| p.kind.clone()).collect())
+            .unwrap_or_default();
+        Ok(abi::decode(&params, args)?)
+    }
+}
+
+impl Deref for Bytecode {
+    type Target = Bytes;
+
+    fn deref(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytecode {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Display for Bytecode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Bytes> for Bytecode {
+    fn from(code: Bytes) -> Self {
+        Self(code)
+    }
+}
+
+impl From<Bytecode> for Bytes {
+    fn from(code: Bytecode) -> Self {
+        code.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::parse_abi;
+
+    #[test]
+    fn empty_code() {
+        let code: Bytecode = serde_json::from_str("\"0x\"").unwrap();
+        assert!(code.is_empty());
+        assert!(!code.contains_delegatecall());
+        assert_eq!(code.selector_at(0), None);
+    }
+
+    #[test]
+    fn finds_delegatecall_outside_push_data() {
+        // PUSH2 0xf4f4, POP
+        let code = Bytecode::from(Bytes::from(vec![0x61, 0xf4, 0xf4, 0x50]));
+        assert!(!code.is_empty());
+        assert!(!code.contains_delegatecall());
+
+        // PUSH1 0x00, DELEGATECALL
+        let code = Bytecode::from(Bytes::from(vec![0x60, 0x00, 0xf4]));
+        assert!(code.contains_delegatecall());
+    }
+
+    #[test]
+    fn reads_selector() {
+        // PUSH4 0xa9059cbb
+        let code = Bytecode::from(Bytes::from(vec![0x63, 0xa9, 0x05, 0x9c, 0xbb]));
+        assert_eq!(code.selector_at(1), Some([0xa9, 0x05, 0x9c, 0xbb]));
+        assert_eq!(code.selector_at(2), None);
+        assert_eq!(code.selector_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn decodes_constructor_args() {
+        let abi = parse_abi(&["constructor(uint256 supply, bool flag)"]).unwrap();
+        let creation = Bytecode::from(Bytes::from(vec![0x60, 0x80, 0x60, 0x40]));
+        let args = vec![Token::Uint(1000u64.into()), Token::Bool(true)];
+        let init_code = [creation.to_vec(), abi::encode(&args)].concat();
+
+        assert_eq!(creation.decode_constructor(&abi, &init_code).unwrap(), args);
+        assert!(creation.decode_constructor(&abi, &init_code[1..]).is_err());
+    }
+}
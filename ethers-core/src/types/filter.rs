@@ -132,6 +132,24 @@ pub struct Filter {
 }
 
 impl Filter {
+    /// Creates a new, empty filter which matches all logs of all addresses in the default block
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// Match all ERC20 `Transfer` events of a token in a range of blocks
+    ///
+    /// ```rust
+    /// # use ethers_core::types::{Address, Filter};
+    /// # fn main() {
+    /// let token: Address = "0x6B175474E89094C44Da98b954EedeAC495271d0F".parse().unwrap();
+    /// let filter = Filter::new()
+    ///     .address(token)
+    ///     .event("Transfer(address,address,uint256)")
+    ///     .from_block(14_000_000u64)
+    ///     .to_block(14_000_100u64);
+    /// # }
+    /// ```
     pub fn new() -> Self {
         Self::default()
     }
@@ -203,6 +221,8 @@ impl Filter {
         self
     }
 
+    /// Sets the `fromBlock` of the filter, replacing a block hash set via
+    /// [`Filter::at_block_hash`]
     #[allow(clippy::wrong_self_convention)]
     #[must_use]
     pub fn from_block<T: Into<BlockNumber>>(mut self, block: T) -> Self {
@@ -210,6 +230,8 @@ impl Filter {
         self
     }
 
+    /// Sets the `toBlock` of the filter, replacing a block hash set via
+    /// [`Filter::at_block_hash`]
     #[allow(clippy::wrong_self_convention)]
     #[must_use]
     pub fn to_block<T: Into<BlockNumber>>(mut self, block: T) -> Self {
@@ -217,6 +239,11 @@ impl Filter {
         self
     }
 
+    /// Only match logs of the block with the given hash, see
+    /// [EIP-234](https://eips.ethereum.org/EIPS/eip-234).
+    ///
+    /// `blockHash` is mutually exclusive with `fromBlock` and `toBlock`, so this replaces any
+    /// block range of the filter.
     #[allow(clippy::wrong_self_convention)]
     #[must_use]
     pub fn at_block_hash<T: Into<H256>>(mut self, hash: T) -> Self {
@@ -297,6 +324,21 @@ impl Filter {
         self
     }
 
+    /// Matches any value at the given topic position, clearing a previously set topic.
+    ///
+    /// Wildcard positions before a set topic are serialized as `null`, e.g. the ERC20 `Transfer`
+    /// events to a recipient from any sender are matched by
+    /// `filter.event("Transfer(address,address,uint256)").topic_any(1).topic2(recipient)`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than 3
+    #[must_use]
+    pub fn topic_any(mut self, index: usize) -> Self {
+        self.topics[index] = None;
+        self
+    }
+
     pub fn is_paginatable(&self) -> bool {
         self.get_from_block().is_some()
     }
@@ -962,6 +1004,52 @@ mod tests {
         assert_eq!(ser, json!({ "address" : addr, "topics": [t0, t1_padded, t2, t3_padded]}));
     }
 
+    #[test]
+    fn filter_serialization_wildcard_topics() {
+        let recipient: Address = "f817796F60D268A36a57b8D2dF1B97B14C0D0E1d".parse().unwrap();
+        let sender: Address = "0000000000000000000000000000000000000001".parse().unwrap();
+        let t0 = H256::from(keccak256("Transfer(address,address,uint256)".as_bytes()));
+        let t2 = H256::from(recipient);
+
+        let filter = Filter::new()
+            .event("Transfer(address,address,uint256)")
+            .topic1(H256::from(sender))
+            .topic2(t2)
+            .topic_any(1);
+        assert!(filter.topics[1].is_none());
+        assert_eq!(serialize(&filter), json!({ "topics": [t0, null, t2] }));
+
+        // topic0 may be a wildcard too, e.g. to match anonymous events
+        let filter = Filter::new().topic_any(0).topic1(t2);
+        assert_eq!(serialize(&filter), json!({ "topics": [null, t2] }));
+
+        // trailing wildcards are omitted
+        let filter = Filter::new().event("Transfer(address,address,uint256)").topic_any(3);
+        assert_eq!(serialize(&filter), json!({ "topics": [t0] }));
+    }
+
+    #[test]
+    fn filter_block_hash_excludes_range() {
+        let hash = H256::random();
+
+        let filter = Filter::new().from_block(1u64).to_block(2u64).at_block_hash(hash);
+        assert_eq!(serialize(&filter), json!({ "blockHash": hash, "topics": [] }));
+        assert_eq!(filter.get_block_hash(), Some(hash));
+        assert_eq!(filter.get_from_block(), None);
+
+        let filter = filter.from_block(1u64);
+        assert_eq!(serialize(&filter), json!({ "fromBlock": "0x1", "topics": [] }));
+        assert_eq!(filter.get_block_hash(), None);
+
+        let err =
+            serde_json::from_value::<Filter>(json!({ "blockHash": hash, "fromBlock": "0x1" }))
+                .unwrap_err();
+        assert!(err.to_string().contains("not allowed with blockHash"));
+        let err = serde_json::from_value::<Filter>(json!({ "toBlock": "0x1", "blockHash": hash }))
+            .unwrap_err();
+        assert!(err.to_string().contains("not allowed with blockHash"));
+    }
+
     fn build_bloom(address: Address, topic1: H256, topic2: H256) -> Bloom {
         let mut block_bloom = Bloom::default();
         block_bloom.accrue(BloomInput::Raw(&address[..]));
@@ -59,6 +59,15 @@ pub struct Log {
     pub removed: Option<bool>,
 }
 
+impl Log {
+    /// Returns true if the log was removed due to a chain reorganization.
+    ///
+    /// Nodes that omit the `removed` field only return valid logs, so a missing value is `false`.
+    pub fn is_removed(&self) -> bool {
+        self.removed.unwrap_or_default()
+    }
+}
+
 impl rlp::Encodable for Log {
     fn rlp_append(&self, s: &mut rlp::RlpStream) {
         s.begin_list(3);
@@ -69,3 +78,44 @@ impl rlp::Encodable for Log {
 }
 
 // TODO: Implement more common types - or adjust this to work with all Tokenizable items
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_log_without_topics() {
+        let log: Log = serde_json::from_str(
+            r#"{
+                "address": "0x4a90b1c2e1d9d6d8bae4a0e2b5e2f3fcf2a5e2b1",
+                "topics": [],
+                "data": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "blockHash": "0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70",
+                "blockNumber": "0x5bad55",
+                "transactionHash": "0xea6d2ae4d29af93bcf3bd7d5b248368a6a1f0bb91d895d630b1d5c7b9fd3a1c1",
+                "transactionIndex": "0x1",
+                "logIndex": "0x2"
+            }"#,
+        )
+        .unwrap();
+        assert!(log.topics.is_empty());
+        assert_eq!(log.removed, None);
+        assert!(!log.is_removed());
+        assert_eq!(log.block_number, Some(0x5bad55u64.into()));
+    }
+
+    #[test]
+    fn deserialize_removed_log() {
+        let log: Log = serde_json::from_str(
+            r#"{
+                "address": "0x4a90b1c2e1d9d6d8bae4a0e2b5e2f3fcf2a5e2b1",
+                "topics": ["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"],
+                "data": "0x",
+                "removed": true
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(log.topics.len(), 1);
+        assert!(log.is_removed());
+    }
+}
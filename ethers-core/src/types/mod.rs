@@ -35,8 +35,11 @@ pub use i256::{ParseI256Error, Sign, I256};
 mod bytes;
 pub use self::bytes::{deserialize_bytes, serialize_bytes, Bytes, ParseBytesError};
 
+mod bytecode;
+pub use bytecode::Bytecode;
+
 mod block;
-pub use block::{Block, BlockId, BlockNumber, TimeError};
+pub use block::{Block, BlockError, BlockId, BlockNumber, TimeError};
 
 #[cfg(feature = "celo")]
 pub use block::Randomness;
@@ -44,6 +47,9 @@ pub use block::Randomness;
 mod log;
 pub use log::Log;
 
+mod withdrawal;
+pub use withdrawal::Withdrawal;
+
 mod filter;
 pub use filter::*;
 
@@ -135,11 +135,41 @@ impl Signature {
     }
 
     /// Copies and serializes `self` into a new `Vec` with the recovery id included
+    ///
+    /// The bytes are laid out as `r || s || v` and `v` is kept as is, e.g. `27` or `28` for
+    /// signatures produced by `eth_sign`.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_vec(&self) -> Vec<u8> {
         self.into()
     }
 
+    /// Returns the `0x`-prefixed hex encoding of the 65 signature bytes, see [`Signature::to_vec`]
+    pub fn to_hex(&self) -> String {
+        format!("0x{self}")
+    }
+
+    /// Parses a signature from the hex encoding of its 65 bytes, with or without `0x` prefix
+    pub fn from_hex(s: &str) -> Result<Self, SignatureError> {
+        s.parse()
+    }
+
+    /// Returns the signature with `v` converted to the given convention: `27` or `28` if no
+    /// chain id is given, otherwise the [EIP-155](https://eips.ethereum.org/EIPS/eip-155)
+    /// `v` for `chain_id`.
+    ///
+    /// Signatures with an invalid `v` are returned unchanged.
+    pub fn normalize_v(&self, chain_id: Option<u64>) -> Signature {
+        let recovery_id = normalize_recovery_id(self.v);
+        if recovery_id > 1 {
+            return *self
+        }
+        let v = match chain_id {
+            Some(chain_id) => chain_id * 2 + 35 + recovery_id as u64,
+            None => 27 + recovery_id as u64,
+        };
+        Signature { v, ..*self }
+    }
+
     /// Decodes a signature from RLP bytes, assuming no RLP header
     pub(crate) fn decode_signature(buf: &mut &[u8]) -> Result<Self, open_fastrlp::DecodeError> {
         let v = u64::decode(buf)?;
@@ -308,4 +338,39 @@ mod tests {
 
         assert_eq!(s1, s2);
     }
+
+    #[test]
+    fn signature_hex_roundtrip() {
+        // signature of "Some data" as produced by `eth_sign`, see `recover_web3_signature`
+        let hex = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+        let signature = Signature::from_hex(hex).unwrap();
+        assert_eq!(signature.v, 28);
+        assert_eq!(signature.to_hex(), hex);
+        assert_eq!(signature.to_hex().len(), 132);
+
+        let bytes = signature.to_vec();
+        assert_eq!(bytes.len(), 65);
+        assert_eq!(bytes[64], 0x1c);
+        assert_eq!(Signature::try_from(&bytes[..]).unwrap(), signature);
+
+        assert!(matches!(Signature::from_hex("0x1234"), Err(SignatureError::InvalidLength(2))));
+    }
+
+    #[test]
+    fn signature_normalize_v() {
+        let signature = Signature::from_str(
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        ).unwrap();
+
+        let eip155 = signature.normalize_v(Some(1));
+        assert_eq!(eip155.v, 38);
+        assert_eq!(u8::from(eip155.recovery_id().unwrap()), 1);
+        assert_eq!(eip155.normalize_v(None), signature);
+
+        let parity = Signature { v: 1, ..signature };
+        assert_eq!(parity.normalize_v(None), signature);
+
+        let invalid = Signature { v: 5, ..signature };
+        assert_eq!(invalid.normalize_v(Some(1)), invalid);
+    }
 }
@@ -12,6 +12,21 @@ pub enum SyncingStatus {
     IsSyncing(Box<SyncProgress>),
 }
 
+impl SyncingStatus {
+    /// Returns true if the node is still syncing, i.e. its responses may be stale
+    pub fn is_syncing(&self) -> bool {
+        matches!(self, SyncingStatus::IsSyncing(_))
+    }
+
+    /// Returns the sync progress if the node is still syncing
+    pub fn progress(&self) -> Option<&SyncProgress> {
+        match self {
+            SyncingStatus::IsFalse => None,
+            SyncingStatus::IsSyncing(progress) => Some(progress),
+        }
+    }
+}
+
 impl Serialize for SyncingStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -141,6 +156,11 @@ mod tests {
     }"#;
 
         let sync: SyncingStatus = serde_json::from_str(s).unwrap();
+        assert!(sync.is_syncing());
+        let progress = sync.progress().unwrap();
+        assert_eq!(progress.starting_block, U64::from(0xea97eeu64));
+        assert_eq!(progress.current_block, U64::from(0xeaa2b4u64));
+        assert_eq!(progress.highest_block, U64::from(0xeaa329u64));
         match sync {
             SyncingStatus::IsFalse => {
                 panic!("unexpected variant")
@@ -154,6 +174,8 @@ mod tests {
         let s = r#"false"#;
 
         let sync: SyncingStatus = serde_json::from_str(s).unwrap();
+        assert!(!sync.is_syncing());
+        assert!(sync.progress().is_none());
         match sync {
             SyncingStatus::IsFalse => {}
             SyncingStatus::IsSyncing(_) => {
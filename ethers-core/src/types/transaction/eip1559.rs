@@ -71,7 +71,7 @@ pub struct Eip1559TransactionRequest {
     /// baseFeePerGas + maxPriorityFeePerGas is “refunded” to the user.
     pub max_fee_per_gas: Option<U256>,
 
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default, rename = "chainId")]
     /// Chain ID (None for mainnet)
     pub chain_id: Option<U64>,
@@ -283,3 +283,9 @@ impl From<&Transaction> for Eip1559TransactionRequest {
         }
     }
 }
+
+impl From<Transaction> for Eip1559TransactionRequest {
+    fn from(tx: Transaction) -> Eip1559TransactionRequest {
+        (&tx).into()
+    }
+}
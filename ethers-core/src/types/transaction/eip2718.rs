@@ -417,6 +417,12 @@ impl From<&Transaction> for TypedTransaction {
     }
 }
 
+impl From<Transaction> for TypedTransaction {
+    fn from(tx: Transaction) -> TypedTransaction {
+        (&tx).into()
+    }
+}
+
 impl TypedTransaction {
     pub fn as_legacy_ref(&self) -> Option<&TransactionRequest> {
         match self {
@@ -808,4 +814,42 @@ mod tests {
             assert_eq!(tx0, tx1);
         }
     }
+
+    #[test]
+    fn converts_fetched_transactions_into_requests() {
+        let tx = Transaction {
+            from: Address::random(),
+            to: Some(Address::random()),
+            nonce: 7u64.into(),
+            value: 100u64.into(),
+            gas: 21_000u64.into(),
+            input: vec![1, 2, 3].into(),
+            max_fee_per_gas: Some(2_000u64.into()),
+            max_priority_fee_per_gas: Some(100u64.into()),
+            chain_id: Some(1u64.into()),
+            block_number: Some(15_000_000u64.into()),
+            transaction_type: Some(2u64.into()),
+            ..Default::default()
+        };
+
+        // `from` is shadowed by the getter of the sender
+        let typed: TypedTransaction = tx.clone().into();
+        let request = match &typed {
+            TypedTransaction::Eip1559(request) => request,
+            other => panic!("expected an EIP-1559 transaction, got {other:?}"),
+        };
+        assert_eq!(request.from, Some(tx.from));
+        assert_eq!(request.to, tx.to.map(NameOrAddress::Address));
+        assert_eq!(request.nonce, Some(tx.nonce));
+        assert_eq!(request.max_fee_per_gas, tx.max_fee_per_gas);
+        assert_eq!(request.max_priority_fee_per_gas, tx.max_priority_fee_per_gas);
+        assert_eq!(typed.data(), Some(&tx.input));
+        assert_eq!(typed.chain_id(), Some(1u64.into()));
+
+        let legacy: TransactionRequest =
+            Transaction { gas_price: Some(5u64.into()), transaction_type: None, ..tx }.into();
+        assert_eq!(legacy.gas_price, Some(5u64.into()));
+        assert_eq!(legacy.gas, Some(21_000u64.into()));
+        assert_eq!(legacy.value, Some(100u64.into()));
+    }
 }
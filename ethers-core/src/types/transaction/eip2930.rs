@@ -186,6 +186,12 @@ impl From<&Transaction> for Eip2930TransactionRequest {
     }
 }
 
+impl From<Transaction> for Eip2930TransactionRequest {
+    fn from(tx: Transaction) -> Eip2930TransactionRequest {
+        (&tx).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
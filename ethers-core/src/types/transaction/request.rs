@@ -1,8 +1,10 @@
 //! Transaction types
 use super::{decode_to, extract_chain_id, rlp_opt, NUM_TX_FIELDS};
 use crate::{
+    abi::{Abi, AbiError, DecodedCall, FunctionExt},
     types::{
-        Address, Bytes, NameOrAddress, Signature, SignatureError, Transaction, H256, U256, U64,
+        Address, Bytes, NameOrAddress, Selector, Signature, SignatureError, Transaction, H256,
+        U256, U64,
     },
     utils::keccak256,
 };
@@ -56,7 +58,7 @@ pub struct TransactionRequest {
     pub nonce: Option<U256>,
 
     /// Chain ID (None for mainnet)
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default, rename = "chainId")]
     pub chain_id: Option<U64>,
 
@@ -149,6 +151,26 @@ impl TransactionRequest {
         self
     }
 
+    /// Returns the function selector, i.e. the first 4 bytes of the `data` field, or `None` if
+    /// `data` is shorter than that
+    pub fn data_selector(&self) -> Option<Selector> {
+        let data = self.data.as_ref()?;
+        data.get(..4).map(|selector| selector.try_into().expect("4 bytes; qed"))
+    }
+
+    /// Decodes the `data` field as a call to one of the functions of the given ABI, matched by its
+    /// selector.
+    ///
+    /// Fails with [`AbiError::WrongSelector`] if `data` is missing, or it does not start with the
+    /// selector of any of the ABI's functions.
+    pub fn decode_calldata(&self, abi: &Abi) -> Result<DecodedCall, AbiError> {
+        let selector = self.data_selector().ok_or(AbiError::WrongSelector)?;
+        let function =
+            abi.functions().find(|f| f.selector() == selector).ok_or(AbiError::WrongSelector)?;
+        let args = function.decode_call(self.data.as_deref().unwrap_or_default())?;
+        Ok(DecodedCall { function: function.clone(), args })
+    }
+
     /// Hashes the transaction's data with the provided chain id
     pub fn sighash(&self) -> H256 {
         match self.chain_id {
@@ -314,6 +336,12 @@ impl From<&Transaction> for TransactionRequest {
     }
 }
 
+impl From<Transaction> for TransactionRequest {
+    fn from(tx: Transaction) -> TransactionRequest {
+        (&tx).into()
+    }
+}
+
 // Separate impl block for the celo-specific fields
 #[cfg(feature = "celo")]
 impl TransactionRequest {
@@ -353,10 +381,46 @@ impl TransactionRequest {
 #[cfg(not(feature = "celo"))]
 mod tests {
     use super::*;
-    use crate::types::{transaction::eip2718::TypedTransaction, Bytes, NameOrAddress, Signature};
+    use crate::{
+        abi::{parse_abi, Token},
+        types::{transaction::eip2718::TypedTransaction, Bytes, NameOrAddress, Signature},
+    };
     use rlp::{Decodable, Rlp};
     use std::str::FromStr;
 
+    #[test]
+    fn decode_calldata() {
+        let abi = parse_abi(&[
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function approve(address spender, uint256 amount) returns (bool)",
+        ])
+        .unwrap();
+        let to = Address::repeat_byte(0x11);
+        let args = vec![Token::Address(to), Token::Uint(100u64.into())];
+        let data = abi.function("transfer").unwrap().encode_call(&args).unwrap();
+
+        let tx = TransactionRequest::new().to(to).data(data.clone());
+        assert_eq!(tx.data_selector(), Some(abi.function("transfer").unwrap().selector()));
+
+        let call = tx.decode_calldata(&abi).unwrap();
+        assert_eq!(call.function.name, "transfer");
+        assert_eq!(call.args, args);
+
+        // no or too short data
+        assert_eq!(TransactionRequest::new().data_selector(), None);
+        let tx = TransactionRequest::new().data(data[..3].to_vec());
+        assert_eq!(tx.data_selector(), None);
+        assert!(matches!(tx.decode_calldata(&abi), Err(AbiError::WrongSelector)));
+
+        // unknown selector
+        let tx = TransactionRequest::new().data(vec![0u8; 36]);
+        assert!(matches!(tx.decode_calldata(&abi), Err(AbiError::WrongSelector)));
+
+        // truncated arguments
+        let tx = TransactionRequest::new().data(data[..20].to_vec());
+        assert!(matches!(tx.decode_calldata(&abi), Err(AbiError::DecodingError(_))));
+    }
+
     #[test]
     fn encode_decode_rlp() {
         let tx = TransactionRequest::new()
@@ -392,6 +456,17 @@ mod tests {
         let got_sighash = hex::encode(tx.sighash().as_bytes());
         assert_eq!(expected_sighash, got_sighash);
     }
+    #[test]
+    fn serializes_chain_id() {
+        let tx = TransactionRequest::new().to(Address::zero());
+        assert!(serde_json::to_value(&tx).unwrap().get("chainId").is_none());
+
+        let tx = tx.chain_id(5u64);
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(json["chainId"], "0x5");
+        assert_eq!(serde_json::from_value::<TransactionRequest>(json).unwrap(), tx);
+    }
+
     #[test]
     fn decode_unsigned_transaction() {
         let _res: TransactionRequest = serde_json::from_str(
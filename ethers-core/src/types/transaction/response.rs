@@ -435,6 +435,22 @@ pub struct TransactionReceipt {
     pub effective_gas_price: Option<U256>,
 }
 
+impl TransactionReceipt {
+    /// Returns the amount of wei paid for the transaction's gas, i.e. `gas_used *
+    /// effective_gas_price`.
+    ///
+    /// Returns `None` if either field is missing, e.g. on networks that predate EIP-1559.
+    pub fn transaction_cost(&self) -> Option<U256> {
+        Some(self.gas_used? * self.effective_gas_price?)
+    }
+
+    /// Returns whether the transaction succeeded, or `None` for pre-Byzantium receipts which
+    /// carry a state `root` instead of a `status`.
+    pub fn success(&self) -> Option<bool> {
+        self.status.map(|status| status == U64::one())
+    }
+}
+
 impl rlp::Encodable for TransactionReceipt {
     fn rlp_append(&self, s: &mut RlpStream) {
         s.begin_list(4);
@@ -527,6 +543,31 @@ mod tests {
         assert_eq!(receipt.effective_gas_price.unwrap().as_u64(), 0x3b9aca07);
     }
 
+    #[test]
+    fn receipt_cost_and_status() {
+        // mainnet tx 0x611b173b0e0dfda94da7bfb6cb77c9f1c03e2f2149ba060e6bddfaa219942369
+        let receipt = TransactionReceipt {
+            gas_used: Some(0x1b9229u64.into()),
+            effective_gas_price: Some(0xa02ffee00u64.into()),
+            status: Some(1u64.into()),
+            ..Default::default()
+        };
+        assert_eq!(receipt.transaction_cost(), Some(77_696_227_000_000_000u64.into()));
+        assert_eq!(receipt.success(), Some(true));
+
+        let failed = TransactionReceipt { status: Some(0u64.into()), ..receipt.clone() };
+        assert_eq!(failed.success(), Some(false));
+
+        let legacy = TransactionReceipt {
+            effective_gas_price: None,
+            status: None,
+            root: Some(H256::random()),
+            ..receipt
+        };
+        assert_eq!(legacy.transaction_cost(), None);
+        assert_eq!(legacy.success(), None);
+    }
+
     #[test]
     fn decode_london_tx() {
         let tx: Transaction = serde_json::from_value(serde_json::json!({"accessList":[{"address":"0x8ba1f109551bd432803012645ac136ddd64dba72","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000000","0x0000000000000000000000000000000000000000000000000000000000000042"]}],"blockHash":"0x55ae43d3511e327dc532855510d110676d340aa1bbba369b4b98896d86559586","blockNumber":"0xa3d322","chainId":"0x3","from":"0x541d6a0e9ca9e7a083e41e2e178eef9f22d7492e","gas":"0x6a40","gasPrice":"0x3b9aca07","hash":"0x824384376c5972498c6fcafe71fd8cad1689f64e7d5e270d025a898638c0c34d","input":"0x","maxFeePerGas":"0x3b9aca0e","maxPriorityFeePerGas":"0x3b9aca00","nonce":"0x2","r":"0xf13b5088108f783f4b6048d4be456971118aabfb88be96bb541d734b6c2b20dc","s":"0x13fb7eb25a7d5df42a176cd4c6a086e19163ed7cd8ffba015f939d24f66bc17a","to":"0x8210357f377e901f18e45294e86a2a32215cc3c9","transactionIndex":"0xd","type":"0x2","v":"0x1","value":"0x7b"})).unwrap();
@@ -0,0 +1,17 @@
+use crate::types::{Address, U256, U64};
+use serde::{Deserialize, Serialize};
+
+/// A validator withdrawal from the consensus layer, see
+/// [EIP-4895](https://eips.ethereum.org/EIPS/eip-4895)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Withdrawal {
+    /// Monotonically increasing identifier issued by the consensus layer
+    pub index: U64,
+    /// Index of the validator associated with the withdrawal
+    pub validator_index: U64,
+    /// Recipient of the withdrawn ether
+    pub address: Address,
+    /// Value of the withdrawal in gwei
+    pub amount: U256,
+}
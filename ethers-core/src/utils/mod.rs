@@ -74,6 +74,10 @@ pub enum ConversionError {
     ParseOverflow,
     #[error(transparent)]
     ParseI256Error(#[from] ParseI256Error),
+    #[error("invalid address `{0}`, expected `0x` followed by 40 hex characters")]
+    InvalidAddress(String),
+    #[error("invalid EIP-55 checksum of address `{0}`")]
+    InvalidAddressChecksum(String),
 }
 
 /// 1 Ether = 1e18 Wei == 0x0de0b6b3a7640000 Wei
@@ -414,8 +418,75 @@ pub fn to_checksum(addr: &Address, chain_id: Option<u8>) -> String {
     })
 }
 
-/// Returns a bytes32 string representation of text. If the length of text exceeds 32 bytes,
-/// an error is returned.
+/// Parses an EIP-55 checksummed address, see also [`to_checksum`].
+///
+/// In contrast to [`Address::from_str`](std::str::FromStr), which accepts any hex string of the
+/// right length, this requires the `0x` prefix and fails with
+/// [`ConversionError::InvalidAddressChecksum`] if the mixed-case checksum does not match. This
+/// catches mistyped or truncated addresses. All-lowercase addresses, which have no checksum, are
+/// rejected, see [`parse_checksummed_lenient`] to accept them as well.
+///
+/// ```
+/// use ethers_core::utils::parse_checksummed;
+///
+/// let addr = parse_checksummed("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", None).unwrap();
+/// assert!(parse_checksummed("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed", None).is_err());
+/// ```
+pub fn parse_checksummed(addr: &str, chain_id: Option<u8>) -> Result<Address, ConversionError> {
+    parse_address_with_checksum(addr, chain_id, true)
+}
+
+/// Parses an address like [`parse_checksummed`], but also accepts all-lowercase addresses, e.g. for
+/// backwards compatibility with inputs that were never checksummed. Mixed-case addresses must still
+/// have a valid checksum.
+///
+/// ```
+/// use ethers_core::utils::parse_checksummed_lenient;
+///
+/// let lowercase = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+/// let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+/// let addr = parse_checksummed_lenient(lowercase, None).unwrap();
+/// assert_eq!(parse_checksummed_lenient(checksummed, None).unwrap(), addr);
+/// assert!(parse_checksummed_lenient("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed", None).is_err());
+/// ```
+pub fn parse_checksummed_lenient(
+    addr: &str,
+    chain_id: Option<u8>,
+) -> Result<Address, ConversionError> {
+    parse_address_with_checksum(addr, chain_id, false)
+}
+
+fn parse_address_with_checksum(
+    addr: &str,
+    chain_id: Option<u8>,
+    require_checksum: bool,
+) -> Result<Address, ConversionError> {
+    let mut bytes = [0u8; 20];
+    let hex_str = addr
+        .strip_prefix("0x")
+        .filter(|hex_str| hex::decode_to_slice(hex_str, &mut bytes).is_ok())
+        .ok_or_else(|| ConversionError::InvalidAddress(addr.to_string()))?;
+
+    let address = Address::from(bytes);
+    let unchecked = !require_checksum && !hex_str.bytes().any(|b| b.is_ascii_uppercase());
+    if !unchecked && to_checksum(&address, chain_id) != addr {
+        return Err(ConversionError::InvalidAddressChecksum(addr.to_string()))
+    }
+    Ok(address)
+}
+
+/// Returns a bytes32 string representation of text, i.e. its UTF-8 bytes right-padded with zeros.
+/// If the length of text exceeds 32 bytes, an error is returned.
+///
+/// Unlike `formatBytes32String` of ethers.js, text of exactly 32 bytes is accepted, so the result
+/// is not necessarily null-terminated.
+///
+/// ```
+/// use ethers_core::utils::{format_bytes32_string, parse_bytes32_string};
+///
+/// let symbol = format_bytes32_string("MKR").unwrap();
+/// assert_eq!(parse_bytes32_string(&symbol).unwrap(), "MKR");
+/// ```
 pub fn format_bytes32_string(text: &str) -> Result<[u8; 32], ConversionError> {
     let str_bytes: &[u8] = text.as_bytes();
     if str_bytes.len() > 32 {
@@ -428,7 +499,8 @@ pub fn format_bytes32_string(text: &str) -> Result<[u8; 32], ConversionError> {
     Ok(bytes32)
 }
 
-/// Returns the decoded string represented by the bytes32 encoded data.
+/// Returns the decoded string represented by the bytes32 encoded data, i.e. the UTF-8 string up
+/// to the first zero byte.
 pub fn parse_bytes32_string(bytes: &[u8; 32]) -> Result<&str, ConversionError> {
     let mut length = 0;
     while length < 32 && bytes[length] != 0 {
@@ -943,6 +1015,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_checksummed_addresses() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let addr = parse_checksummed(checksummed, None).unwrap();
+        assert_eq!(addr, checksummed.parse::<Address>().unwrap());
+
+        // rsk checksums include the chain id
+        let rsk = "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD";
+        assert_eq!(parse_checksummed(rsk, Some(30)).unwrap(), addr);
+        assert!(matches!(
+            parse_checksummed(rsk, None),
+            Err(ConversionError::InvalidAddressChecksum(_))
+        ));
+
+        for invalid_checksum in [
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+        ] {
+            assert!(matches!(
+                parse_checksummed(invalid_checksum, None),
+                Err(ConversionError::InvalidAddressChecksum(_))
+            ));
+        }
+
+        for invalid in [
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed00",
+            "0xZaAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ] {
+            assert!(matches!(
+                parse_checksummed(invalid, None),
+                Err(ConversionError::InvalidAddress(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn parses_lowercase_addresses_leniently() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let addr = checksummed.parse::<Address>().unwrap();
+        assert_eq!(parse_checksummed_lenient(checksummed, None).unwrap(), addr);
+        let lowercase = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        assert_eq!(parse_checksummed_lenient(lowercase, None).unwrap(), addr);
+        assert_eq!(parse_checksummed_lenient(lowercase, Some(30)).unwrap(), addr);
+
+        // mixed case is still validated
+        for invalid_checksum in [
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+        ] {
+            assert!(matches!(
+                parse_checksummed_lenient(invalid_checksum, None),
+                Err(ConversionError::InvalidAddressChecksum(_))
+            ));
+        }
+        assert!(matches!(
+            parse_checksummed_lenient("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", None),
+            Err(ConversionError::InvalidAddress(_))
+        ));
+    }
+
     #[test]
     fn bytes32_string_parsing() {
         let text_bytes_list = vec![
@@ -991,6 +1126,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn bytes32_string_roundtrip_utf8() {
+        let text = "Ünïcødé 🦀";
+        let bytes = format_bytes32_string(text).unwrap();
+        assert_eq!(parse_bytes32_string(&bytes).unwrap(), text);
+
+        // 11 crabs are 44 bytes
+        assert!(matches!(
+            format_bytes32_string(&"🦀".repeat(11)).unwrap_err(),
+            ConversionError::TextTooLong
+        ));
+
+        let mut invalid = [0u8; 32];
+        invalid[..2].copy_from_slice(&[0xc3, 0x28]);
+        assert!(matches!(
+            parse_bytes32_string(&invalid).unwrap_err(),
+            ConversionError::Utf8Error(_)
+        ));
+    }
+
     #[test]
     fn test_eip1559_default_estimator() {
         // If the base fee is below the triggering base fee, we should get the default priority fee
@@ -1,20 +1,26 @@
 use crate::{
     gas_oracle::{GasOracle, GasOracleMiddleware},
-    NonceManagerMiddleware, SignerMiddleware,
+    CachingMiddleware, NonceManagerMiddleware, SignerMiddleware,
 };
 use ethers_core::types::Address;
 use ethers_providers::Middleware;
 use ethers_signers::Signer;
+use std::time::Duration;
 
 /// A builder trait to compose different [`Middleware`](ethers_providers::Middleware) layers
 /// and then build a composed [`Provider`](ethers_providers::Provider) architecture.
 /// [`Middleware`](ethers_providers::Middleware) composition acts in a wrapping fashion. Adding a
 /// new layer results in wrapping its predecessor.
 ///
+/// Retries are handled at the transport level rather than by a middleware, e.g. a
+/// [`Provider`](ethers_providers::Provider) created with
+/// [`Provider::new_client`](ethers_providers::Provider::new_client) retries requests that were
+/// rate limited by the node.
+///
 /// ```rust
 /// use ethers_providers::{Middleware, Provider, Http};
 /// use std::sync::Arc;
-/// use std::convert::TryFrom;
+/// use std::{convert::TryFrom, time::Duration};
 /// use ethers_signers::{LocalWallet, Signer};
 /// use ethers_middleware::{*, gas_escalator::*, gas_oracle::*};
 ///
@@ -28,6 +34,7 @@ use ethers_signers::Signer;
 ///     let provider = Provider::<Http>::try_from("http://localhost:8545")
 ///         .unwrap()
 ///         .wrap_into(|p| GasEscalatorMiddleware::new(p, escalator, Frequency::PerBlock))
+///         .cache(Duration::from_secs(60))
 ///         .gas_oracle(gas_oracle)
 ///         .with_signer(signer)
 ///         .nonce_manager(address); // Outermost layer
@@ -86,6 +93,12 @@ pub trait MiddlewareBuilder: Middleware + Sized + 'static {
     {
         GasOracleMiddleware::new(self, gas_oracle)
     }
+
+    /// Wraps `self` inside a [`CachingMiddleware`](crate::CachingMiddleware), caching responses
+    /// for `ttl`.
+    fn cache(self, ttl: Duration) -> CachingMiddleware<Self> {
+        CachingMiddleware::new(self, ttl)
+    }
 }
 
 impl<M> MiddlewareBuilder for M where M: Middleware + Sized + 'static {}
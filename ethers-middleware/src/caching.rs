@@ -0,0 +1,305 @@
+use async_trait::async_trait;
+use ethers_core::types::{
+    transaction::eip2718::TypedTransaction, BlockId, BlockNumber, Bytes, NameOrAddress, H256, U256,
+};
+use ethers_providers::{FromErr, JsonRpcError, Middleware};
+use futures_locks::RwLock;
+use instant::Instant;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, future::Future, time::Duration};
+use thiserror::Error;
+
+/// The default maximum number of responses held by a [`CachingMiddleware`]
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// The classes of requests a [`CachingMiddleware`] is able to cache. Every class can be assigned
+/// its own time-to-live via [`CachingMiddleware::ttl`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheClass {
+    /// `eth_chainId`
+    ChainId,
+    /// `eth_getCode`
+    Code,
+    /// `eth_call`
+    Call,
+    /// `eth_getBalance`, `eth_getStorageAt` and `eth_getTransactionCount`
+    State,
+}
+
+#[derive(Debug)]
+struct Entry {
+    inserted: Instant,
+    last_used: Instant,
+    class: CacheClass,
+    value: Value,
+}
+
+/// Middleware used for caching the responses of read-only requests.
+///
+/// A request is only cached if its result can not change over time: requests that are pinned to a
+/// specific block, either by number or by hash, and `eth_chainId`. Requests against a block tag
+/// like `latest` or `pending` and state changing requests like `eth_sendTransaction` are always
+/// forwarded to the inner middleware.
+///
+/// Once the cache is full the least recently used response is evicted.
+///
+/// ```no_run
+/// use ethers_providers::{Middleware, Provider, Http};
+/// use ethers_middleware::{CachingMiddleware, caching::CacheClass};
+/// use ethers_core::types::{Address, BlockNumber};
+/// use std::{convert::TryFrom, time::Duration};
+///
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
+/// let provider = CachingMiddleware::new(provider, Duration::from_secs(60))
+///     .ttl(CacheClass::Code, Duration::from_secs(3600));
+///
+/// let address = Address::random();
+/// let block = Some(BlockNumber::Number(15_000_000u64.into()).into());
+/// // only the first call hits the node
+/// let code = provider.get_code(address, block).await?;
+/// let code = provider.get_code(address, block).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CachingMiddleware<M> {
+    inner: M,
+    default_ttl: Duration,
+    ttls: HashMap<CacheClass, Duration>,
+    capacity: usize,
+    cache: RwLock<HashMap<String, Entry>>,
+}
+
+impl<M> CachingMiddleware<M>
+where
+    M: Middleware,
+{
+    /// Instantiates the caching middleware. Cached responses are valid for `ttl` unless a different
+    /// time-to-live is configured for their [`CacheClass`].
+    pub fn new(inner: M, ttl: Duration) -> Self {
+        Self {
+            inner,
+            default_ttl: ttl,
+            ttls: HashMap::new(),
+            capacity: DEFAULT_CACHE_CAPACITY,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the time-to-live for responses of the given class
+    #[must_use]
+    pub fn ttl(mut self, class: CacheClass, ttl: Duration) -> Self {
+        self.ttls.insert(class, ttl);
+        self
+    }
+
+    /// Sets the maximum number of cached responses
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Returns the number of currently cached responses, including expired ones that were not
+    /// evicted yet
+    pub async fn len(&self) -> usize {
+        self.cache.read().await.len()
+    }
+
+    /// Returns true if there are no cached responses
+    pub async fn is_empty(&self) -> bool {
+        self.cache.read().await.is_empty()
+    }
+
+    /// Removes all cached responses
+    pub async fn clear(&self) {
+        self.cache.write().await.clear();
+    }
+
+    fn ttl_of(&self, class: CacheClass) -> Duration {
+        self.ttls.get(&class).copied().unwrap_or(self.default_ttl)
+    }
+
+    /// Returns the cached response for `(method, params)` or calls `fetch` and caches its result.
+    async fn get_or_fetch<P, R, F, Fut>(
+        &self,
+        class: CacheClass,
+        method: &str,
+        params: P,
+        fetch: F,
+    ) -> Result<R, CachingMiddlewareError<M>>
+    where
+        P: Serialize,
+        R: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<R, M::Error>>,
+    {
+        // params that can not be serialized (e.g. ENS names) are never cached
+        let key = match serde_json::to_string(&params) {
+            Ok(params) => format!("{method}:{params}"),
+            Err(_) => return fetch().await.map_err(CachingMiddlewareError::MiddlewareError),
+        };
+        let ttl = self.ttl_of(class);
+
+        {
+            let mut cache = self.cache.write().await;
+            if let Some(entry) = cache.get_mut(&key) {
+                if entry.inserted.elapsed() < ttl {
+                    entry.last_used = Instant::now();
+                    return Ok(serde_json::from_value(entry.value.clone())?)
+                }
+                cache.remove(&key);
+            }
+        }
+
+        let res = fetch().await.map_err(CachingMiddlewareError::MiddlewareError)?;
+        let value = serde_json::to_value(&res)?;
+
+        let mut cache = self.cache.write().await;
+        if self.capacity == 0 {
+            return Ok(res)
+        }
+        if !cache.contains_key(&key) && cache.len() >= self.capacity {
+            // make room by dropping expired entries first, then the least recently used one
+            cache.retain(|_, entry| entry.inserted.elapsed() < self.ttl_of(entry.class));
+            if cache.len() >= self.capacity {
+                if let Some(lru) = cache
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone())
+                {
+                    cache.remove(&lru);
+                }
+            }
+        }
+        let now = Instant::now();
+        cache.insert(key, Entry { inserted: now, last_used: now, class, value });
+
+        Ok(res)
+    }
+}
+
+/// Returns true if the block is pinned to a specific number or hash, i.e. the response of a
+/// request against it can not change (barring reorgs).
+fn is_pinned(block: Option<BlockId>) -> bool {
+    matches!(block, Some(BlockId::Hash(_)) | Some(BlockId::Number(BlockNumber::Number(_))))
+}
+
+#[derive(Error, Debug)]
+/// Thrown when an error happens at the Caching Middleware
+pub enum CachingMiddlewareError<M: Middleware> {
+    /// Thrown when the internal middleware errors
+    #[error("{0}")]
+    MiddlewareError(M::Error),
+
+    /// Thrown when a response could not be (de)serialized for caching
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+}
+
+impl<M: Middleware> FromErr<M::Error> for CachingMiddlewareError<M> {
+    fn from(src: M::Error) -> Self {
+        CachingMiddlewareError::MiddlewareError(src)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<M> Middleware for CachingMiddleware<M>
+where
+    M: Middleware,
+{
+    type Error = CachingMiddlewareError<M>;
+    type Provider = M::Provider;
+    type Inner = M;
+
+    fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            CachingMiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
+    async fn get_chainid(&self) -> Result<U256, Self::Error> {
+        self.get_or_fetch(CacheClass::ChainId, "eth_chainId", (), || self.inner.get_chainid()).await
+    }
+
+    async fn get_code<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        at: T,
+        block: Option<BlockId>,
+    ) -> Result<Bytes, Self::Error> {
+        let at = at.into();
+        if !is_pinned(block) {
+            return self.inner.get_code(at, block).await.map_err(FromErr::from)
+        }
+        self.get_or_fetch(CacheClass::Code, "eth_getCode", (&at, block), || {
+            self.inner.get_code(at.clone(), block)
+        })
+        .await
+    }
+
+    async fn call(
+        &self,
+        tx: &TypedTransaction,
+        block: Option<BlockId>,
+    ) -> Result<Bytes, Self::Error> {
+        if !is_pinned(block) {
+            return self.inner.call(tx, block).await.map_err(FromErr::from)
+        }
+        self.get_or_fetch(CacheClass::Call, "eth_call", (tx, block), || self.inner.call(tx, block))
+            .await
+    }
+
+    async fn get_balance<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        from: T,
+        block: Option<BlockId>,
+    ) -> Result<U256, Self::Error> {
+        let from = from.into();
+        if !is_pinned(block) {
+            return self.inner.get_balance(from, block).await.map_err(FromErr::from)
+        }
+        self.get_or_fetch(CacheClass::State, "eth_getBalance", (&from, block), || {
+            self.inner.get_balance(from.clone(), block)
+        })
+        .await
+    }
+
+    async fn get_transaction_count<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        from: T,
+        block: Option<BlockId>,
+    ) -> Result<U256, Self::Error> {
+        let from = from.into();
+        if !is_pinned(block) {
+            return self.inner.get_transaction_count(from, block).await.map_err(FromErr::from)
+        }
+        self.get_or_fetch(CacheClass::State, "eth_getTransactionCount", (&from, block), || {
+            self.inner.get_transaction_count(from.clone(), block)
+        })
+        .await
+    }
+
+    async fn get_storage_at<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        from: T,
+        location: H256,
+        block: Option<BlockId>,
+    ) -> Result<H256, Self::Error> {
+        let from = from.into();
+        if !is_pinned(block) {
+            return self.inner.get_storage_at(from, location, block).await.map_err(FromErr::from)
+        }
+        self.get_or_fetch(CacheClass::State, "eth_getStorageAt", (&from, location, block), || {
+            self.inner.get_storage_at(from.clone(), location, block)
+        })
+        .await
+    }
+}
@@ -7,7 +7,9 @@ pub use linear::LinearGasPrice;
 
 use async_trait::async_trait;
 use ethers_core::types::{BlockId, TransactionRequest, TxHash, U256};
-use ethers_providers::{interval, FromErr, Middleware, PendingTransaction, StreamExt};
+use ethers_providers::{
+    interval, FromErr, JsonRpcError, Middleware, PendingTransaction, StreamExt,
+};
 use futures_util::lock::Mutex;
 use instant::Instant;
 use std::{pin::Pin, sync::Arc};
@@ -98,6 +100,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            GasEscalatorError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     async fn send_transaction<T: Into<TypedTransaction> + Send + Sync>(
         &self,
         tx: T,
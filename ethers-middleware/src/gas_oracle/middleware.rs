@@ -1,7 +1,7 @@
 use super::{GasOracle, GasOracleError};
 use async_trait::async_trait;
 use ethers_core::types::{transaction::eip2718::TypedTransaction, *};
-use ethers_providers::{FromErr, Middleware, PendingTransaction};
+use ethers_providers::{FromErr, JsonRpcError, Middleware, PendingTransaction};
 use thiserror::Error;
 
 /// Middleware used for fetching gas prices over an API instead of `eth_gasPrice`.
@@ -56,6 +56,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            MiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     async fn fill_transaction(
         &self,
         tx: &mut TypedTransaction,
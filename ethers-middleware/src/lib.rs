@@ -37,6 +37,11 @@ pub use policy::PolicyMiddleware;
 pub mod timelag;
 pub use timelag::TimeLag;
 
+/// The [Caching](crate::CachingMiddleware) middleware caches the responses of read-only requests
+/// which are pinned to a specific block
+pub mod caching;
+pub use caching::CachingMiddleware;
+
 /// The [MiddlewareBuilder](crate::MiddlewareBuilder) provides a way to compose many
 /// [`Middleware`](ethers_providers::Middleware) in a concise way
 pub mod builder;
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use ethers_core::types::{transaction::eip2718::TypedTransaction, *};
-use ethers_providers::{FromErr, Middleware, PendingTransaction};
+use ethers_providers::{FromErr, JsonRpcError, Middleware, PendingTransaction};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use thiserror::Error;
 
@@ -30,17 +30,18 @@ where
         nonce.into()
     }
 
+    /// Fetches the nonce of the address the first time it is called and returns the current
+    /// nonce.
+    ///
+    /// Unless a `block` is given, the pending nonce is fetched, i.e. transactions that are already
+    /// in the mempool are counted and not replaced.
     pub async fn initialize_nonce(
         &self,
         block: Option<BlockId>,
     ) -> Result<U256, NonceManagerError<M>> {
         // initialize the nonce the first time the manager is called
         if !self.initialized.load(Ordering::SeqCst) {
-            let nonce = self
-                .inner
-                .get_transaction_count(self.address, block)
-                .await
-                .map_err(FromErr::from)?;
+            let nonce = self.fetch_nonce(block).await?;
             self.nonce.store(nonce.as_u64(), Ordering::SeqCst);
             self.initialized.store(true, Ordering::SeqCst);
         }
@@ -54,17 +55,19 @@ where
     ) -> Result<U256, NonceManagerError<M>> {
         // initialize the nonce the first time the manager is called
         if !self.initialized.load(Ordering::SeqCst) {
-            let nonce = self
-                .inner
-                .get_transaction_count(self.address, block)
-                .await
-                .map_err(FromErr::from)?;
+            let nonce = self.fetch_nonce(block).await?;
             self.nonce.store(nonce.as_u64(), Ordering::SeqCst);
             self.initialized.store(true, Ordering::SeqCst);
         }
 
         Ok(self.next())
     }
+
+    /// Fetches the nonce of the address as of `block`, the pending block by default
+    async fn fetch_nonce(&self, block: Option<BlockId>) -> Result<U256, NonceManagerError<M>> {
+        let block = block.unwrap_or_else(|| BlockNumber::Pending.into());
+        self.inner.get_transaction_count(self.address, Some(block)).await.map_err(FromErr::from)
+    }
 }
 
 #[derive(Error, Debug)]
@@ -95,6 +98,12 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            NonceManagerError::MiddlewareError(err) => M::as_error_response(err),
+        }
+    }
+
     async fn fill_transaction(
         &self,
         tx: &mut TypedTransaction,
@@ -124,7 +133,7 @@ where
         match self.inner.send_transaction(tx.clone(), block).await {
             Ok(tx_hash) => Ok(tx_hash),
             Err(err) => {
-                let nonce = self.get_transaction_count(self.address, block).await?;
+                let nonce = self.fetch_nonce(block).await?;
                 if nonce != self.nonce.load(Ordering::SeqCst).into() {
                     // try re-submitting the transaction with the correct nonce if there
                     // was a nonce mismatch
@@ -1,5 +1,5 @@
 use ethers_core::types::{transaction::eip2718::TypedTransaction, BlockId};
-use ethers_providers::{FromErr, Middleware, PendingTransaction};
+use ethers_providers::{FromErr, JsonRpcError, Middleware, PendingTransaction};
 
 use async_trait::async_trait;
 use std::fmt::Debug;
@@ -95,6 +95,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            PolicyMiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     /// This ensures the tx complies with the registered policy.
     /// If so then this simply delegates the transaction to the inner middleware
     async fn send_transaction<T: Into<TypedTransaction> + Send + Sync>(
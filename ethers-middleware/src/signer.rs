@@ -2,7 +2,7 @@ use ethers_core::types::{
     transaction::{eip2718::TypedTransaction, eip2930::AccessListWithGasUsed},
     Address, BlockId, Bytes, Chain, Signature, TransactionRequest, U256,
 };
-use ethers_providers::{maybe, FromErr, Middleware, PendingTransaction};
+use ethers_providers::{maybe, FromErr, JsonRpcError, Middleware, PendingTransaction};
 use ethers_signers::Signer;
 use std::convert::TryFrom;
 
@@ -149,6 +149,34 @@ where
         Ok(tx.rlp_signed(&signature))
     }
 
+    /// Fills the missing fields of the transaction and signs it without broadcasting it, e.g. to
+    /// include it in a bundle or to send it later.
+    ///
+    /// Returns the RLP encoding of the signed transaction, which can be broadcast with
+    /// [`Middleware::send_raw_transaction`]. Fails with
+    /// [`SignerMiddlewareError::WrongSigner`] if the transaction's `from` is not the signer.
+    pub async fn fill_and_sign_transaction<T: Into<TypedTransaction> + Send + Sync>(
+        &self,
+        tx: T,
+        block: Option<BlockId>,
+    ) -> Result<Bytes, SignerMiddlewareError<M, S>> {
+        let mut tx = tx.into();
+        if tx.from().map_or(false, |from| *from != self.address) {
+            return Err(SignerMiddlewareError::WrongSigner)
+        }
+
+        self.fill_transaction(&mut tx, block).await?;
+        self.sign_transaction(tx).await
+    }
+
+    /// Signs a message with the signer, see [`Signer::sign_message`]
+    pub async fn sign_message<T: AsRef<[u8]> + Send + Sync>(
+        &self,
+        message: T,
+    ) -> Result<Signature, SignerMiddlewareError<M, S>> {
+        self.signer.sign_message(message).await.map_err(SignerMiddlewareError::SignerError)
+    }
+
     /// Returns the client's address
     pub fn address(&self) -> Address {
         self.address
@@ -214,6 +242,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            SignerMiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     /// Returns the client's address
     fn default_sender(&self) -> Option<Address> {
         Some(self.address)
@@ -517,6 +552,81 @@ mod tests {
         assert_eq!(tx.from, acc);
     }
 
+    #[tokio::test]
+    async fn fills_from_regardless_of_gas() {
+        let (provider, mock) = Provider::mocked();
+        let key = LocalWallet::new(&mut rand::thread_rng()).with_chain_id(1u32);
+        let address = key.address();
+        let client = SignerMiddleware::new(provider, key);
+        let request = TransactionRequest::pay(Address::zero(), 100u64).gas_price(1u64).nonce(0u64);
+
+        // estimating the gas must happen on behalf of the signer
+        let mut tx: TypedTransaction = request.clone().into();
+        mock.push(U256::from(21_000u64)).unwrap();
+        client.fill_transaction(&mut tx, None).await.unwrap();
+        assert_eq!(tx.from(), Some(&address));
+        assert_eq!(tx.gas(), Some(&21_000u64.into()));
+        let mut estimated = tx.clone();
+        estimated.as_legacy_mut().unwrap().gas = None;
+        mock.assert_request("eth_estimateGas", [estimated]).unwrap();
+
+        // a pre-populated gas limit must not prevent the `from` field from being set
+        let mut tx: TypedTransaction = request.gas(50_000u64).into();
+        client.fill_transaction(&mut tx, None).await.unwrap();
+        assert_eq!(tx.from(), Some(&address));
+        assert_eq!(tx.gas(), Some(&50_000u64.into()));
+        mock.assert_request("eth_estimateGas", ()).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn fills_and_signs_without_sending() {
+        let (provider, mock) = Provider::mocked();
+        let key = LocalWallet::new(&mut rand::thread_rng()).with_chain_id(1u32);
+        let address = key.address();
+        let client = SignerMiddleware::new(provider, key.clone());
+        let request =
+            TransactionRequest::pay(Address::zero(), 100u64).gas_price(1u64).gas(21_000u64);
+
+        // only the nonce is missing
+        mock.push(U256::from(7u64)).unwrap();
+        let signed = client.fill_and_sign_transaction(request.clone(), None).await.unwrap();
+        mock.assert_request("eth_getTransactionCount", (address, "latest")).unwrap();
+        mock.assert_request("eth_sendRawTransaction", ()).unwrap_err();
+
+        let tx: TypedTransaction = request.clone().from(address).nonce(7u64).chain_id(1u64).into();
+        let signature = key.sign_transaction(&tx).await.unwrap();
+        assert_eq!(signed, tx.rlp_signed(&signature));
+
+        let err = client
+            .fill_and_sign_transaction(request.from(Address::repeat_byte(0x11)), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SignerMiddlewareError::WrongSigner));
+
+        let signature = client.sign_message("hello").await.unwrap();
+        signature.verify("hello", address).unwrap();
+    }
+
+    #[tokio::test]
+    async fn fills_and_enforces_signer_chain_id() {
+        let (provider, _mock) = Provider::mocked();
+        let key = LocalWallet::new(&mut rand::thread_rng()).with_chain_id(5u32);
+        let client = SignerMiddleware::new(provider, key);
+        let request = TransactionRequest::pay(Address::zero(), 100u64)
+            .gas_price(1u64)
+            .gas(21_000u64)
+            .nonce(0u64);
+
+        // a missing chain id is populated from the signer, so the tx is EIP-155 protected
+        let mut tx: TypedTransaction = request.clone().into();
+        client.fill_transaction(&mut tx, None).await.unwrap();
+        assert_eq!(tx.chain_id(), Some(5u64.into()));
+
+        // a chain id that differs from the signer's is refused instead of being signed
+        let err = client.send_transaction(request.chain_id(1u64), None).await.unwrap_err();
+        assert!(matches!(err, SignerMiddlewareError::DifferentChainID));
+    }
+
     #[tokio::test]
     async fn converts_tx_to_legacy_to_match_chain() {
         let eip1559 = Eip1559TransactionRequest {
@@ -6,7 +6,7 @@ use ethers_core::types::{
 use std::sync::Arc;
 use thiserror::Error;
 
-use ethers_providers::{FromErr, Middleware};
+use ethers_providers::{FromErr, JsonRpcError, Middleware};
 
 type TimeLagResult<T, M> = Result<T, TimeLagError<M>>;
 
@@ -110,6 +110,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            TimeLagError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     async fn get_block_number(&self) -> Result<ethers_core::types::U64, Self::Error> {
         self.inner()
             .get_block_number()
@@ -1,7 +1,7 @@
 use super::{Transformer, TransformerError};
 use async_trait::async_trait;
 use ethers_core::types::{transaction::eip2718::TypedTransaction, *};
-use ethers_providers::{FromErr, Middleware, PendingTransaction};
+use ethers_providers::{FromErr, JsonRpcError, Middleware, PendingTransaction};
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -54,6 +54,13 @@ where
         &self.inner
     }
 
+    fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+        match err {
+            TransformerMiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+            _ => None,
+        }
+    }
+
     async fn send_transaction<Tx: Into<TypedTransaction> + Send + Sync>(
         &self,
         tx: Tx,
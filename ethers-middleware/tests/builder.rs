@@ -1,6 +1,9 @@
 #![cfg(not(target_arch = "wasm32"))]
 
-use ethers_core::{rand::thread_rng, types::U64};
+use ethers_core::{
+    rand::thread_rng,
+    types::{U256, U64},
+};
 use ethers_middleware::{
     builder::MiddlewareBuilder,
     gas_escalator::{Frequency, GasEscalatorMiddleware, GeometricGasPrice},
@@ -10,6 +13,7 @@ use ethers_middleware::{
 };
 use ethers_providers::{Middleware, Provider};
 use ethers_signers::{LocalWallet, Signer};
+use std::time::Duration;
 
 #[tokio::test]
 async fn build_raw_middleware_stack() {
@@ -65,3 +69,22 @@ async fn build_declarative_middleware_stack() {
     mock.assert_request("eth_blockNumber", ()).unwrap();
     mock.assert_request("eth_blockNumber", ()).unwrap_err();
 }
+
+#[tokio::test]
+async fn build_caching_middleware_stack() {
+    let (provider, mock) = Provider::mocked();
+
+    let signer = LocalWallet::new(&mut thread_rng());
+    let address = signer.address();
+
+    let provider =
+        provider.cache(Duration::from_secs(60)).with_signer(signer).nonce_manager(address);
+
+    // the chain id is only fetched once
+    mock.push(U256::from(1u64)).unwrap();
+    assert_eq!(provider.get_chainid().await.unwrap(), U256::from(1u64));
+    assert_eq!(provider.get_chainid().await.unwrap(), U256::from(1u64));
+
+    mock.assert_request("eth_chainId", ()).unwrap();
+    mock.assert_request("eth_chainId", ()).unwrap_err();
+}
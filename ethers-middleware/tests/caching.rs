@@ -0,0 +1,79 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use ethers_core::types::{Address, BlockNumber, Bytes, U256};
+use ethers_middleware::{caching::CacheClass, CachingMiddleware};
+use ethers_providers::{Middleware, Provider};
+use std::time::Duration;
+
+#[tokio::test]
+async fn caches_pinned_requests() {
+    let (provider, mock) = Provider::mocked();
+    let provider = CachingMiddleware::new(provider, Duration::from_secs(60));
+
+    let address = Address::random();
+    let block = Some(BlockNumber::Number(100u64.into()).into());
+    let code = Bytes::from(vec![0x60, 0x80]);
+
+    // a single response is enough for both calls
+    mock.push::<Bytes, _>(code.clone()).unwrap();
+    assert_eq!(provider.get_code(address, block).await.unwrap(), code);
+    assert_eq!(provider.get_code(address, block).await.unwrap(), code);
+
+    mock.assert_request("eth_getCode", (address, "0x64")).unwrap();
+    mock.assert_request("eth_getCode", (address, "0x64")).unwrap_err();
+
+    // a different block is a different request
+    let other = Some(BlockNumber::Number(101u64.into()).into());
+    mock.push::<Bytes, _>(Bytes::default()).unwrap();
+    assert_eq!(provider.get_code(address, other).await.unwrap(), Bytes::default());
+
+    provider.clear().await;
+    assert!(provider.is_empty().await);
+    mock.push::<Bytes, _>(code.clone()).unwrap();
+    assert_eq!(provider.get_code(address, block).await.unwrap(), code);
+}
+
+#[tokio::test]
+async fn does_not_cache_latest() {
+    let (provider, mock) = Provider::mocked();
+    let provider = CachingMiddleware::new(provider, Duration::from_secs(60));
+
+    let address = Address::random();
+    mock.push(U256::from(1u64)).unwrap();
+    mock.push(U256::from(2u64)).unwrap();
+
+    assert_eq!(provider.get_balance(address, None).await.unwrap(), 2u64.into());
+    assert_eq!(
+        provider.get_balance(address, Some(BlockNumber::Latest.into())).await.unwrap(),
+        1u64.into()
+    );
+    assert!(provider.is_empty().await);
+}
+
+#[tokio::test]
+async fn expires_and_evicts_entries() {
+    let (provider, mock) = Provider::mocked();
+    let provider = CachingMiddleware::new(provider, Duration::from_secs(60))
+        .ttl(CacheClass::ChainId, Duration::ZERO)
+        .capacity(1);
+
+    // zero ttl, the chain id is fetched again
+    mock.push(U256::from(1u64)).unwrap();
+    mock.push(U256::from(1u64)).unwrap();
+    provider.get_chainid().await.unwrap();
+    provider.get_chainid().await.unwrap();
+    mock.assert_request("eth_chainId", ()).unwrap();
+    mock.assert_request("eth_chainId", ()).unwrap();
+
+    let block = Some(BlockNumber::Number(100u64.into()).into());
+    let (a, b) = (Address::random(), Address::random());
+    mock.push(U256::from(1u64)).unwrap();
+    mock.push(U256::from(2u64)).unwrap();
+    mock.push(U256::from(3u64)).unwrap();
+    assert_eq!(provider.get_balance(a, block).await.unwrap(), 3u64.into());
+    // evicts the balance of `a`
+    assert_eq!(provider.get_balance(b, block).await.unwrap(), 2u64.into());
+    assert_eq!(provider.get_balance(b, block).await.unwrap(), 2u64.into());
+    assert_eq!(provider.get_balance(a, block).await.unwrap(), 1u64.into());
+    assert_eq!(provider.len().await, 1);
+}
@@ -53,3 +53,25 @@ async fn nonce_manager() {
 
     assert_eq!(nonces, (nonce..nonce + num_tx as u64).collect::<Vec<_>>())
 }
+
+#[tokio::test]
+async fn nonce_manager_starts_at_pending_nonce() {
+    let (provider, mock) = ethers_providers::Provider::mocked();
+    let address = Address::random();
+    let provider = NonceManagerMiddleware::new(provider, address);
+
+    // the pending nonce counts the transaction that is still in the mempool
+    mock.push(U256::from(4u64)).unwrap();
+    assert_eq!(provider.initialize_nonce(None).await.unwrap(), 4u64.into());
+    mock.assert_request("eth_getTransactionCount", (address, "pending")).unwrap();
+    assert_eq!(provider.next(), 4u64.into());
+    assert_eq!(provider.next(), 5u64.into());
+
+    mock.push(U256::from(3u64)).unwrap();
+    mock.push(U256::from(4u64)).unwrap();
+    assert_eq!(provider.get_pending_nonce(address).await.unwrap(), 4u64.into());
+    mock.assert_request("eth_getTransactionCount", (address, "pending")).unwrap();
+    // confirmed only
+    assert_eq!(provider.get_transaction_count(address, None).await.unwrap(), 3u64.into());
+    mock.assert_request("eth_getTransactionCount", (address, "latest")).unwrap();
+}
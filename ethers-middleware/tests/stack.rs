@@ -1,6 +1,10 @@
 #![cfg(all(not(target_arch = "wasm32"), not(feature = "celo")))]
 
-use ethers_core::{rand::thread_rng, types::TransactionRequest, utils::Anvil};
+use ethers_core::{
+    rand::thread_rng,
+    types::{Address, TransactionRequest, U256},
+    utils::Anvil,
+};
 use ethers_middleware::{
     gas_escalator::{Frequency, GasEscalatorMiddleware, GeometricGasPrice},
     gas_oracle::{GasCategory, GasNow, GasOracleMiddleware},
@@ -49,6 +53,40 @@ async fn mock_with_middleware() {
     mock.assert_request("eth_blockNumber", ()).unwrap_err();
 }
 
+#[tokio::test]
+async fn simulation_reverts_through_middleware() {
+    use ethers_contract::{Contract, ContractError};
+    use ethers_core::{abi::parse_abi, types::Bytes};
+    use ethers_providers::JsonRpcError;
+
+    let (provider, mock) = Provider::mocked();
+    let signer = LocalWallet::new(&mut thread_rng());
+    let address = signer.address();
+    let provider = SignerMiddleware::new(provider, signer);
+    let provider = NonceManagerMiddleware::new(provider, address);
+
+    let abi = parse_abi(&["function withdraw(uint256 amount) returns (bool)"]).unwrap();
+    let contract = Contract::new(Address::zero(), abi, provider);
+    let call = contract.method::<_, bool>("withdraw", U256::one()).unwrap();
+
+    // the revert is decoded from the error of the outermost middleware
+    let data: Bytes = "0x08c379a0".parse().unwrap();
+    mock.push_error(JsonRpcError {
+        code: 3,
+        message: "execution reverted".to_string(),
+        data: Some(serde_json::json!(data)),
+    });
+    match call.simulate().await.unwrap_err() {
+        ContractError::Reverted(revert) => assert_eq!(revert.data, Some(data)),
+        err => panic!("expected a revert, got {:?}", err),
+    }
+
+    // the call went through the signer, which set the sender
+    let mut tx = call.tx.clone();
+    tx.set_from(address);
+    mock.assert_request("eth_call", (tx, "latest")).unwrap();
+}
+
 #[tokio::test]
 async fn can_stack_middlewares() {
     let anvil = Anvil::new().block_time(5u64).spawn();
@@ -1,5 +1,5 @@
-//! ERC related utilities. Only supporting NFTs for now.
-use ethers_core::types::{Address, Selector, U256};
+//! ERC related utilities. Only supporting NFTs and ERC-20 transfers for now.
+use ethers_core::types::{Address, Log, Selector, H256, U256, U64};
 
 use serde::Deserialize;
 use std::str::FromStr;
@@ -11,6 +11,9 @@ pub const ERC721_OWNER_SELECTOR: Selector = [0x63, 0x52, 0x21, 0x1e];
 /// balanceOf(address owner, uint256 tokenId)
 pub const ERC1155_BALANCE_SELECTOR: Selector = [0x00, 0xfd, 0xd5, 0x8e];
 
+/// The signature of the ERC-20 `Transfer` event
+pub const ERC20_TRANSFER_EVENT: &str = "Transfer(address,address,uint256)";
+
 const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
 
 /// An ERC 721 or 1155 token
@@ -95,3 +98,62 @@ pub fn http_link_ipfs(url: Url) -> Result<Url, String> {
         .join(url.to_string().trim_start_matches("ipfs://").trim_start_matches("ipfs/"))
         .map_err(|e| e.to_string())
 }
+
+/// An ERC-20 `Transfer(address indexed from, address indexed to, uint256 value)` event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferEvent {
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+    /// Hash of the transaction that emitted the event, `None` if it is pending
+    pub tx_hash: Option<H256>,
+    /// Number of the block that includes the transaction, `None` if it is pending
+    pub block_number: Option<U64>,
+}
+
+impl TryFrom<&Log> for TransferEvent {
+    type Error = String;
+
+    /// Decodes an ERC-20 transfer log. ERC-721 transfers share the event signature but index the
+    /// token id, they are rejected.
+    fn try_from(log: &Log) -> Result<Self, Self::Error> {
+        match (log.topics.as_slice(), log.data.len()) {
+            ([_, from, to], 32) => Ok(TransferEvent {
+                from: Address::from(*from),
+                to: Address::from(*to),
+                value: U256::from_big_endian(&log.data),
+                tx_hash: log.transaction_hash,
+                block_number: log.block_number,
+            }),
+            _ => Err(format!("Not an ERC-20 transfer log: {log:?}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_core::utils::keccak256;
+
+    #[test]
+    fn decodes_erc20_transfer() {
+        let from = Address::random();
+        let to = Address::random();
+        let mut log = Log {
+            topics: vec![keccak256(ERC20_TRANSFER_EVENT).into(), from.into(), to.into()],
+            data: vec![0u8; 31].into_iter().chain([42]).collect::<Vec<_>>().into(),
+            transaction_hash: Some(H256::random()),
+            ..Default::default()
+        };
+        let transfer = TransferEvent::try_from(&log).unwrap();
+        assert_eq!(transfer.from, from);
+        assert_eq!(transfer.to, to);
+        assert_eq!(transfer.value, 42u64.into());
+        assert_eq!(transfer.tx_hash, log.transaction_hash);
+
+        // ERC-721 transfers index the token id
+        log.topics.push(H256::from_low_u64_be(42));
+        log.data = Default::default();
+        assert!(TransferEvent::try_from(&log).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use ethers_core::types::{SyncingStatus, U64};
+
+/// A summary of a node's health as returned by
+/// [`Middleware::check_health`](crate::Middleware::check_health).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// The sync status of the node, see `eth_syncing`
+    pub syncing: SyncingStatus,
+    /// The number of peers connected to the node, see `net_peerCount`
+    pub peer_count: U64,
+    /// The node's client version, see `web3_clientVersion`
+    pub client_version: String,
+}
+
+impl HealthStatus {
+    /// Returns true if the node is done syncing and connected to at least `min_peers` peers
+    pub fn is_healthy(&self, min_peers: u64) -> bool {
+        !self.syncing.is_syncing() && self.peer_count >= U64::from(min_peers)
+    }
+}
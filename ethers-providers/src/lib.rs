@@ -8,7 +8,9 @@ use futures_util::future::join_all;
 pub use transports::*;
 
 mod provider;
-pub use provider::{is_local_endpoint, FilterKind, Provider, ProviderError, ProviderExt};
+pub use provider::{
+    is_local_endpoint, FilterKind, Provider, ProviderError, ProviderExt, RevertInfo,
+};
 
 // types for the admin api
 pub mod admin;
@@ -38,6 +40,9 @@ pub use pubsub::{PubsubClient, SubscriptionStream};
 pub mod call_raw;
 pub mod erc;
 
+mod health;
+pub use health::HealthStatus;
+
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 use ethers_core::types::transaction::{eip2718::TypedTransaction, eip2930::AccessListWithGasUsed};
@@ -166,6 +171,13 @@ pub trait Middleware: Sync + Send + Debug {
         FromErr::from(e)
     }
 
+    /// Returns the error response of the node if it caused `err`, e.g. to decode the revert data of
+    /// a failed `eth_call`. Middleware that wrap the error of their inner middleware should
+    /// delegate to the inner middleware for that case.
+    fn as_error_response(_err: &Self::Error) -> Option<&JsonRpcError> {
+        None
+    }
+
     /// The HTTP or Websocket provider.
     fn provider(&self) -> &Provider<Self::Provider> {
         self.inner().provider()
@@ -311,6 +323,11 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().get_uncle(block_hash_or_number, idx).await.map_err(FromErr::from)
     }
 
+    /// Returns the number of transactions sent from `from`, i.e. its next nonce, as of `block`.
+    ///
+    /// The `latest` block (the default) only counts confirmed transactions, while
+    /// [`BlockNumber::Pending`] also counts transactions in the node's mempool, see
+    /// [`get_pending_nonce`](Self::get_pending_nonce).
     async fn get_transaction_count<T: Into<NameOrAddress> + Send + Sync>(
         &self,
         from: T,
@@ -319,6 +336,22 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().get_transaction_count(from, block).await.map_err(FromErr::from)
     }
 
+    /// Returns true if the network supports EIP-1559 transactions, i.e. the latest block has a
+    /// base fee
+    async fn is_eip1559_enabled(&self) -> Result<bool, Self::Error> {
+        let block = self.get_block(BlockNumber::Latest).await?;
+        Ok(block.map_or(false, |block| block.base_fee_per_gas.is_some()))
+    }
+
+    /// Returns the next nonce of `from` including its pending transactions, i.e. the nonce that
+    /// does not replace a transaction that is still waiting to be mined
+    async fn get_pending_nonce<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        from: T,
+    ) -> Result<U256, Self::Error> {
+        self.get_transaction_count(from, Some(BlockNumber::Pending.into())).await
+    }
+
     async fn estimate_gas(
         &self,
         tx: &TypedTransaction,
@@ -339,6 +372,12 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().syncing().await.map_err(FromErr::from)
     }
 
+    /// Returns true if the node is still syncing, see [`syncing`](Self::syncing). A syncing node
+    /// may return stale data.
+    async fn is_syncing(&self) -> Result<bool, Self::Error> {
+        self.syncing().await.map(|status| status.is_syncing())
+    }
+
     async fn get_chainid(&self) -> Result<U256, Self::Error> {
         self.inner().get_chainid().await.map_err(FromErr::from)
     }
@@ -347,6 +386,22 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().get_net_version().await.map_err(FromErr::from)
     }
 
+    /// Returns the number of peers connected to the node
+    async fn get_net_peer_count(&self) -> Result<U64, Self::Error> {
+        self.inner().get_net_peer_count().await.map_err(FromErr::from)
+    }
+
+    /// Checks the sync status, peer count and client version of the node at once, e.g. to
+    /// sanity check a node before using it.
+    async fn check_health(&self) -> Result<HealthStatus, Self::Error> {
+        let (syncing, peer_count, client_version) = futures_util::try_join!(
+            self.syncing(),
+            self.get_net_peer_count(),
+            self.client_version()
+        )?;
+        Ok(HealthStatus { syncing, peer_count, client_version })
+    }
+
     async fn get_balance<T: Into<NameOrAddress> + Send + Sync>(
         &self,
         from: T,
@@ -391,6 +446,24 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().get_accounts().await.map_err(FromErr::from)
     }
 
+    /// Returns the accounts on the node together with their balances at `block`, e.g. the funded
+    /// accounts of a local development node.
+    ///
+    /// The balances are requested concurrently.
+    async fn get_accounts_with_balances(
+        &self,
+        block: Option<BlockId>,
+    ) -> Result<Vec<(Address, U256)>, Self::Error> {
+        let accounts = self.get_accounts().await?;
+        let balances =
+            join_all(accounts.iter().map(|account| self.get_balance(*account, block))).await;
+        accounts
+            .into_iter()
+            .zip(balances)
+            .map(|(account, balance)| Ok((account, balance?)))
+            .collect()
+    }
+
     async fn send_raw_transaction<'a>(
         &'a self,
         tx: Bytes,
@@ -398,6 +471,17 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().send_raw_transaction(tx).await.map_err(FromErr::from)
     }
 
+    /// Broadcasts a transaction that was signed out of band, e.g. by a hardware wallet or a
+    /// relayer's client, by RLP encoding it together with its signature and sending it via
+    /// [`send_raw_transaction`](Self::send_raw_transaction).
+    async fn send_signed_transaction<'a>(
+        &'a self,
+        tx: &TypedTransaction,
+        signature: &Signature,
+    ) -> Result<PendingTransaction<'a, Self::Provider>, Self::Error> {
+        self.send_raw_transaction(tx.rlp_signed(signature)).await
+    }
+
     /// This returns true if either the middleware stack contains a `SignerMiddleware`, or the
     /// JSON-RPC provider has an unlocked key that can sign using the `eth_sign` call. If none of
     /// the above conditions are met, then the middleware stack is not capable of signing data.
@@ -481,6 +565,16 @@ pub trait Middleware: Sync + Send + Debug {
         self.inner().get_code(at, block).await.map_err(FromErr::from)
     }
 
+    /// Returns the code at the given address as [`Bytecode`], see
+    /// [`get_code`](Self::get_code). An empty code means the address is not a contract.
+    async fn get_bytecode<T: Into<NameOrAddress> + Send + Sync>(
+        &self,
+        at: T,
+        block: Option<BlockId>,
+    ) -> Result<Bytecode, Self::Error> {
+        self.get_code(at, block).await.map(Bytecode::from)
+    }
+
     async fn get_storage_at<T: Into<NameOrAddress> + Send + Sync>(
         &self,
         from: T,
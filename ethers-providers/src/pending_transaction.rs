@@ -1,5 +1,5 @@
 use crate::{stream::interval, JsonRpcClient, Middleware, PinBoxFut, Provider, ProviderError};
-use ethers_core::types::{Transaction, TransactionReceipt, TxHash, U64};
+use ethers_core::types::{Address, Transaction, TransactionReceipt, TxHash, U256, U64};
 use futures_core::stream::Stream;
 use futures_util::stream::StreamExt;
 use pin_project::pin_project;
@@ -23,6 +23,10 @@ use wasm_timer::Delay;
 /// is 1, but may be adjusted with the `confirmations` method. If the transaction does not
 /// have enough confirmations or is not mined, the future will stay in the pending state.
 ///
+/// If the transaction disappears from the mempool because another transaction of the same
+/// sender with the same nonce was mined within the last 10 blocks (e.g. a speed-up or
+/// cancellation), the future resolves to [`ProviderError::TransactionReplaced`] instead.
+///
 /// # Example
 ///
 ///```
@@ -60,10 +64,15 @@ pub struct PendingTransaction<'a, P> {
     state: PendingTxState<'a>,
     interval: Box<dyn Stream<Item = ()> + Send + Unpin>,
     retries_remaining: usize,
+    /// The `(from, nonce)` of the transaction, once it was seen in the mempool
+    sender: Option<(Address, U256)>,
 }
 
 const DEFAULT_RETRIES: usize = 3;
 
+/// The number of blocks searched for the transaction that replaced a pending transaction
+const REPLACEMENT_LOOKBACK: usize = 10;
+
 impl<'a, P: JsonRpcClient> PendingTransaction<'a, P> {
     /// Creates a new pending transaction poller from a hash and a provider
     pub fn new(tx_hash: TxHash, provider: &'a Provider<P>) -> Self {
@@ -76,6 +85,7 @@ impl<'a, P: JsonRpcClient> PendingTransaction<'a, P> {
             state: PendingTxState::InitialDelay(delay),
             interval: Box::new(interval(provider.get_interval())),
             retries_remaining: DEFAULT_RETRIES,
+            sender: None,
         }
     }
 
@@ -196,20 +206,18 @@ impl<'a, P: JsonRpcClient> Future for PendingTransaction<'a, P> {
                 );
 
                 let tx_opt = tx_res.unwrap();
-                // If the tx is no longer in the mempool, return Ok(None)
+                // If the tx is no longer in the mempool, check whether it was replaced
                 if tx_opt.is_none() {
-                    if *this.retries_remaining == 0 {
-                        tracing::debug!("Dropped from mempool, pending tx {:?}", *this.tx_hash);
-                        *this.state = PendingTxState::Completed;
-                        return Poll::Ready(Ok(None))
+                    if let Some((from, nonce)) = *this.sender {
+                        let fut = Box::pin(find_replacement(this.provider, from, nonce));
+                        rewake_with_new_state!(ctx, this, PendingTxState::CheckingReplacement(fut));
                     }
-
-                    *this.retries_remaining -= 1;
-                    rewake_with_new_state!(ctx, this, PendingTxState::PausedGettingTx);
+                    rewake_with_new_state!(ctx, this, PendingTxState::Dropped);
                 }
 
                 // If it hasn't confirmed yet, poll again later
                 let tx = tx_opt.unwrap();
+                *this.sender = Some((tx.from, tx.nonce));
                 rewake_with_new_state_if!(
                     tx.block_number.is_none(),
                     ctx,
@@ -222,6 +230,36 @@ impl<'a, P: JsonRpcClient> Future for PendingTransaction<'a, P> {
                 let fut = Box::pin(this.provider.get_transaction_receipt(*this.tx_hash));
                 rewake_with_new_state!(ctx, this, PendingTxState::GettingReceipt(fut));
             }
+            PendingTxState::CheckingReplacement(fut) => {
+                match futures_util::ready!(fut.as_mut().poll(ctx)) {
+                    Ok(Some(replacement_hash)) => {
+                        tracing::debug!(
+                            "Pending tx {:?} was replaced by {:?}",
+                            *this.tx_hash,
+                            replacement_hash
+                        );
+                        *this.state = PendingTxState::Completed;
+                        return Poll::Ready(Err(ProviderError::TransactionReplaced {
+                            replacement_hash,
+                        }))
+                    }
+                    // not replaced (yet), or the provider errored
+                    _ => {
+                        rewake_with_new_state!(ctx, this, PendingTxState::Dropped);
+                    }
+                }
+            }
+            PendingTxState::Dropped => {
+                // Not in the mempool, poll again unless we are out of retries
+                if *this.retries_remaining == 0 {
+                    tracing::debug!("Dropped from mempool, pending tx {:?}", *this.tx_hash);
+                    *this.state = PendingTxState::Completed;
+                    return Poll::Ready(Ok(None))
+                }
+
+                *this.retries_remaining -= 1;
+                rewake_with_new_state!(ctx, this, PendingTxState::PausedGettingTx);
+            }
             PendingTxState::PausedGettingReceipt => {
                 // Wait the polling period so that we do not spam the chain when no
                 // new block has been mined
@@ -307,6 +345,35 @@ impl<'a, P: JsonRpcClient> Future for PendingTransaction<'a, P> {
     }
 }
 
+/// Returns the hash of the mined transaction of `from` with `nonce`, if there is one within the
+/// last [`REPLACEMENT_LOOKBACK`] blocks.
+async fn find_replacement<P: JsonRpcClient>(
+    provider: &Provider<P>,
+    from: Address,
+    nonce: U256,
+) -> Result<Option<TxHash>, ProviderError> {
+    // the nonce is only used up once a transaction with it was mined
+    if provider.get_transaction_count(from, None).await? <= nonce {
+        return Ok(None)
+    }
+
+    let mut number = provider.get_block_number().await?;
+    for _ in 0..REPLACEMENT_LOOKBACK {
+        if let Some(block) = provider.get_block_with_txs(number).await? {
+            if let Some(tx) =
+                block.transactions.iter().find(|tx| tx.from == from && tx.nonce == nonce)
+            {
+                return Ok(Some(tx.hash))
+            }
+        }
+        if number.is_zero() {
+            break
+        }
+        number -= U64::one();
+    }
+    Ok(None)
+}
+
 impl<'a, P> fmt::Debug for PendingTransaction<'a, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PendingTransaction")
@@ -350,6 +417,12 @@ enum PendingTxState<'a> {
     /// Polling The blockchain to see if the Tx has confirmed or dropped
     GettingTx(PinBoxFut<'a, Option<Transaction>>),
 
+    /// Searching for a mined transaction with the same sender and nonce after the Tx dropped
+    CheckingReplacement(PinBoxFut<'a, Option<TxHash>>),
+
+    /// The Tx is not in the mempool and was not replaced, either retry or give up
+    Dropped,
+
     /// Waiting for interval to elapse before calling API again
     PausedGettingReceipt,
 
@@ -377,6 +450,8 @@ impl<'a> fmt::Debug for PendingTxState<'a> {
             PendingTxState::InitialDelay(_) => "InitialDelay",
             PendingTxState::PausedGettingTx => "PausedGettingTx",
             PendingTxState::GettingTx(_) => "GettingTx",
+            PendingTxState::CheckingReplacement(_) => "CheckingReplacement",
+            PendingTxState::Dropped => "Dropped",
             PendingTxState::PausedGettingReceipt => "PausedGettingReceipt",
             PendingTxState::GettingReceipt(_) => "GettingReceipt",
             PendingTxState::GettingBlockNumber(_, _) => "GettingBlockNumber",
@@ -388,3 +463,60 @@ impl<'a> fmt::Debug for PendingTxState<'a> {
         f.debug_struct("PendingTxState").field("state", &state).finish()
     }
 }
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use ethers_core::types::Block;
+
+    fn pending_tx(hash: TxHash, from: Address, nonce: u64) -> Transaction {
+        Transaction { hash, from, nonce: nonce.into(), ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn detects_replacement() {
+        let (provider, mock) = Provider::mocked();
+        let from = Address::random();
+        let hash = TxHash::random();
+        let replacement = pending_tx(TxHash::random(), from, 5);
+
+        // the mock returns the responses in reverse order
+        let block = Block { transactions: vec![replacement.clone()], ..Default::default() };
+        mock.push(block).unwrap();
+        mock.push(U64::from(10u64)).unwrap();
+        mock.push(U256::from(6u64)).unwrap();
+        mock.push(Option::<Transaction>::None).unwrap();
+        mock.push(pending_tx(hash, from, 5)).unwrap();
+
+        let err = PendingTransaction::new(hash, &provider)
+            .interval(Duration::from_millis(1))
+            .await
+            .unwrap_err();
+        match err {
+            ProviderError::TransactionReplaced { replacement_hash } => {
+                assert_eq!(replacement_hash, replacement.hash)
+            }
+            err => panic!("expected TransactionReplaced, got {err:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_without_replacement() {
+        let (provider, mock) = Provider::mocked();
+        let from = Address::random();
+        let hash = TxHash::random();
+
+        // the nonce was not used by another transaction
+        mock.push(U256::from(5u64)).unwrap();
+        mock.push(Option::<Transaction>::None).unwrap();
+        mock.push(pending_tx(hash, from, 5)).unwrap();
+
+        let receipt = PendingTransaction::new(hash, &provider)
+            .interval(Duration::from_millis(1))
+            .retries(0)
+            .await
+            .unwrap();
+        assert!(receipt.is_none());
+    }
+}
@@ -1,10 +1,12 @@
 use crate::{
     call_raw::CallBuilder,
-    ens, erc, maybe,
+    ens,
+    erc::{self, TransferEvent},
+    maybe,
     pubsub::{PubsubClient, SubscriptionStream},
     stream::{FilterWatcher, DEFAULT_LOCAL_POLL_INTERVAL, DEFAULT_POLL_INTERVAL},
-    FromErr, Http as HttpProvider, JsonRpcClient, JsonRpcClientWrapper, LogQuery, MockProvider,
-    NodeInfo, PeerInfo, PendingTransaction, QuorumProvider, RwClient, SyncingStatus,
+    FromErr, Http as HttpProvider, JsonRpcClient, JsonRpcClientWrapper, JsonRpcError, LogQuery,
+    MockProvider, NodeInfo, PeerInfo, PendingTransaction, QuorumProvider, RwClient, SyncingStatus,
 };
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "ws"))]
@@ -18,9 +20,12 @@ use crate::Middleware;
 use async_trait::async_trait;
 
 use ethers_core::{
-    abi::{self, Detokenize, ParamType},
+    abi::{self, AbiDecode, Detokenize, ParamType},
     types::{
-        transaction::{eip2718::TypedTransaction, eip2930::AccessListWithGasUsed},
+        transaction::{
+            eip2718::TypedTransaction,
+            eip2930::{AccessListWithGasUsed, Eip2930TransactionRequest},
+        },
         Address, Block, BlockId, BlockNumber, BlockTrace, Bytes, EIP1186ProofResponse, FeeHistory,
         Filter, FilterBlockOption, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace,
         Log, NameOrAddress, Selector, Signature, Trace, TraceFilter, TraceType, Transaction,
@@ -97,6 +102,8 @@ pub struct Provider<P> {
     /// Unsupported node client = `Some(None)`
     /// Supported node client = `Some(Some(NodeClient))`
     _node_client: Arc<Mutex<Option<NodeClient>>>,
+    /// The chain id of the node, fetched once by `fill_transaction`
+    _chain_id: Arc<Mutex<Option<u64>>>,
 }
 
 impl<P> AsRef<P> for Provider<P> {
@@ -118,6 +125,10 @@ pub enum ProviderError {
     #[error(transparent)]
     JsonRpcClientError(#[from] Box<dyn std::error::Error + Send + Sync>),
 
+    /// An error response returned by the node
+    #[error(transparent)]
+    JsonRpcError(JsonRpcError),
+
     /// An error during ENS name resolution
     #[error("ens name not found: {0}")]
     EnsError(String),
@@ -146,6 +157,60 @@ pub enum ProviderError {
 
     #[error("Attempted to sign a transaction with no available signer. Hint: did you mean to use a SignerMiddleware?")]
     SignerUnavailable,
+
+    /// Thrown when a pending transaction was replaced by another transaction with the same
+    /// sender and nonce
+    #[error("transaction was replaced by {replacement_hash:?}")]
+    TransactionReplaced { replacement_hash: TxHash },
+
+    /// Thrown when `eth_estimateGas` fails because the transaction would revert
+    #[error("gas estimation failed: {0}")]
+    GasEstimationFailed(RevertInfo),
+
+    /// Thrown when [`Provider::wait_for_block`] times out
+    #[error("timed out waiting for block {0}")]
+    BlockTimeout(U64),
+}
+
+impl ProviderError {
+    /// Returns the error response of the node, if this error is one
+    pub fn as_error_response(&self) -> Option<&JsonRpcError> {
+        match self {
+            ProviderError::JsonRpcError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Details of a call that reverted, as reported by the node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevertInfo {
+    /// The error message of the node, e.g. `execution reverted`
+    pub message: String,
+    /// The raw revert data, if the node included it
+    pub data: Option<Bytes>,
+}
+
+impl RevertInfo {
+    /// Returns the revert info of an error response, if it reports a revert
+    pub fn from_error_response(err: &JsonRpcError) -> Option<Self> {
+        err.is_revert().then(|| Self { message: err.message.clone(), data: err.revert_data() })
+    }
+
+    /// Decodes the revert data as `T`, e.g. a custom error or the enum of all custom errors of a
+    /// contract generated by `abigen!`
+    pub fn decode<T: AbiDecode>(&self) -> Option<T> {
+        T::decode(self.data.as_ref()?).ok()
+    }
+}
+
+impl std::fmt::Display for RevertInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.data {
+            Some(ref data) => write!(f, "{}: {}", self.message, data),
+            None => f.write_str(&self.message),
+        }
+    }
 }
 
 /// Types of filters supported by the JSON-RPC.
@@ -171,7 +236,53 @@ impl<P: JsonRpcClient> Provider<P> {
             interval: None,
             from: None,
             _node_client: Arc::new(Mutex::new(None)),
+            _chain_id: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the chain id of the node, only requesting it the first time
+    async fn cached_chain_id(&self) -> Result<u64, ProviderError> {
+        let mut cached = self._chain_id.lock().await;
+        if let Some(chain_id) = *cached {
+            return Ok(chain_id)
         }
+        let chain_id = self.get_chainid().await?;
+        let chain_id = u64::try_from(chain_id).map_err(|_| {
+            ProviderError::CustomError(format!("chain id {chain_id} does not fit into a u64"))
+        })?;
+        Ok(*cached.insert(chain_id))
+    }
+
+    /// Estimates the EIP-1559 fees on top of the base fee of the latest block
+    async fn eip1559_fees(
+        &self,
+        base_fee_per_gas: U256,
+        estimator: Option<fn(U256, Vec<Vec<U256>>) -> (U256, U256)>,
+    ) -> Result<(U256, U256), ProviderError> {
+        let fee_history = self
+            .fee_history(
+                utils::EIP1559_FEE_ESTIMATION_PAST_BLOCKS,
+                BlockNumber::Latest,
+                &[utils::EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE],
+            )
+            .await?;
+
+        // use the provided fee estimator function, or fallback to the default implementation.
+        let (max_fee_per_gas, max_priority_fee_per_gas) = if let Some(es) = estimator {
+            es(base_fee_per_gas, fee_history.reward)
+        } else {
+            utils::eip1559_default_estimator(base_fee_per_gas, fee_history.reward)
+        };
+
+        Ok((max_fee_per_gas, max_priority_fee_per_gas))
+    }
+
+    /// Forgets the chain id cached by `fill_transaction`, so that the next fill requests it
+    /// again, e.g. after the node switched to another chain.
+    ///
+    /// The cache is shared by all clones of this provider.
+    pub async fn clear_cached_chain_id(&self) {
+        *self._chain_id.lock().await = None;
     }
 
     /// Returns the type of node we're connected to, while also caching the value for use
@@ -310,6 +421,10 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
         p
     }
 
+    fn as_error_response(err: &ProviderError) -> Option<&JsonRpcError> {
+        err.as_error_response()
+    }
+
     fn default_sender(&self) -> Option<Address> {
         self.from
     }
@@ -334,6 +449,12 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
             }
         }
 
+        // the chain id is part of the transaction for signers that only get the serialized request
+        if tx.chain_id().is_none() {
+            let chain_id = self.cached_chain_id().await?;
+            tx.set_chain_id(chain_id);
+        }
+
         // TODO: Join the name resolution and gas price future
 
         // set the ENS name
@@ -342,16 +463,17 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
             tx.set_to(addr);
         }
 
-        // fill gas price
-        match tx {
-            TypedTransaction::Eip2930(_) | TypedTransaction::Legacy(_) => {
-                let gas_price = maybe(tx.gas_price(), self.get_gas_price()).await?;
-                tx.set_gas_price(gas_price);
-            }
-            TypedTransaction::Eip1559(ref mut inner) => {
-                if inner.max_fee_per_gas.is_none() || inner.max_priority_fee_per_gas.is_none() {
+        // fill the EIP-1559 fees, the latest block tells whether the network supports them
+        if let TypedTransaction::Eip1559(ref mut inner) = tx {
+            if inner.max_fee_per_gas.is_none() || inner.max_priority_fee_per_gas.is_none() {
+                let base_fee_per_gas = self
+                    .get_block(BlockNumber::Latest)
+                    .await?
+                    .ok_or_else(|| ProviderError::CustomError("Latest block not found".into()))?
+                    .base_fee_per_gas;
+                if let Some(base_fee_per_gas) = base_fee_per_gas {
                     let (max_fee_per_gas, max_priority_fee_per_gas) =
-                        self.estimate_eip1559_fees(None).await?;
+                        self.eip1559_fees(base_fee_per_gas, None).await?;
                     // we want to avoid overriding the user if either of these
                     // are set. In order to do this, we refuse to override the
                     // `max_fee_per_gas` if already set.
@@ -361,18 +483,51 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
                     //   - first: if set, set to the min(current value, MFPG)
                     //   - second, if still unset, use the RPC estimated amount
                     let mfpg = inner.max_fee_per_gas.get_or_insert(max_fee_per_gas);
-                    inner
+                    inner.max_priority_fee_per_gas = inner
                         .max_priority_fee_per_gas
                         .map(|tip| std::cmp::min(tip, *mfpg))
-                        .get_or_insert(max_priority_fee_per_gas);
-                };
+                        .or(Some(max_priority_fee_per_gas));
+                } else {
+                    // pre-London networks do not accept EIP-1559 transactions, pay a gas price
+                    // instead. A max fee set by the user becomes the gas price, a tip alone does
+                    // not tell what to pay.
+                    if inner.max_fee_per_gas.is_none() && inner.max_priority_fee_per_gas.is_some() {
+                        return Err(ProviderError::CustomError(
+                            "EIP-1559 not activated, set max_fee_per_gas or a gas price".into(),
+                        ))
+                    }
+                    tracing::warn!(
+                        "EIP-1559 is not enabled on the network, sending a legacy transaction"
+                    );
+                    let access_list = inner.access_list.clone();
+                    let legacy: TransactionRequest = inner.clone().into();
+                    *tx = if access_list.0.is_empty() {
+                        TypedTransaction::Legacy(legacy)
+                    } else {
+                        TypedTransaction::Eip2930(Eip2930TransactionRequest::new(
+                            legacy,
+                            access_list,
+                        ))
+                    };
+                }
             }
         }
 
+        // fill gas price
+        if let TypedTransaction::Eip2930(_) | TypedTransaction::Legacy(_) = tx {
+            let gas_price = maybe(tx.gas_price(), self.get_gas_price()).await?;
+            tx.set_gas_price(gas_price);
+        }
+
         // Set gas to estimated value only if it was not set by the caller,
         // even if the access list has been populated and saves gas
         if tx.gas().is_none() {
-            let gas_estimate = self.estimate_gas(tx, block).await?;
+            let gas_estimate = self.estimate_gas(tx, block).await.map_err(|err| {
+                match err.as_error_response().and_then(RevertInfo::from_error_response) {
+                    Some(revert) => ProviderError::GasEstimationFailed(revert),
+                    None => err,
+                }
+            })?;
             tx.set_gas(gas_estimate);
         }
 
@@ -492,23 +647,7 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
             .ok_or_else(|| ProviderError::CustomError("Latest block not found".into()))?
             .base_fee_per_gas
             .ok_or_else(|| ProviderError::CustomError("EIP-1559 not activated".into()))?;
-
-        let fee_history = self
-            .fee_history(
-                utils::EIP1559_FEE_ESTIMATION_PAST_BLOCKS,
-                BlockNumber::Latest,
-                &[utils::EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE],
-            )
-            .await?;
-
-        // use the provided fee estimator function, or fallback to the default implementation.
-        let (max_fee_per_gas, max_priority_fee_per_gas) = if let Some(es) = estimator {
-            es(base_fee_per_gas, fee_history.reward)
-        } else {
-            utils::eip1559_default_estimator(base_fee_per_gas, fee_history.reward)
-        };
-
-        Ok((max_fee_per_gas, max_priority_fee_per_gas))
+        self.eip1559_fees(base_fee_per_gas, estimator).await
     }
 
     /// Gets the accounts on the node
@@ -564,6 +703,11 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
         self.request("net_version", ()).await
     }
 
+    /// Returns the number of peers connected to the node.
+    async fn get_net_peer_count(&self) -> Result<U64, ProviderError> {
+        self.request("net_peerCount", ()).await
+    }
+
     ////// Contract Execution
     //
     // These are relatively low-level calls. The Contracts API should usually be used instead.
@@ -1031,6 +1175,8 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
     /// Returns the details of all transactions currently pending for inclusion in the next
     /// block(s), as well as the ones that are being scheduled for future execution only.
     /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content)
+    ///
+    /// The `txpool` namespace is only served by Geth-compatible nodes.
     async fn txpool_content(&self) -> Result<TxpoolContent, ProviderError> {
         self.request("txpool_content", ()).await
     }
@@ -1038,6 +1184,8 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
     /// Returns a summary of all the transactions currently pending for inclusion in the next
     /// block(s), as well as the ones that are being scheduled for future execution only.
     /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_inspect)
+    ///
+    /// The `txpool` namespace is only served by Geth-compatible nodes.
     async fn txpool_inspect(&self) -> Result<TxpoolInspect, ProviderError> {
         self.request("txpool_inspect", ()).await
     }
@@ -1045,6 +1193,8 @@ impl<P: JsonRpcClient> Middleware for Provider<P> {
     /// Returns the number of transactions currently pending for inclusion in the next block(s), as
     /// well as the ones that are being scheduled for future execution only.
     /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_status)
+    ///
+    /// The `txpool` namespace is only served by Geth-compatible nodes.
     async fn txpool_status(&self) -> Result<TxpoolStatus, ProviderError> {
         self.request("txpool_status", ()).await
     }
@@ -1349,6 +1499,118 @@ impl<P: JsonRpcClient> Provider<P> {
         Ok(())
     }
 
+    /// Subscribes to new block headers and yields only their block numbers.
+    ///
+    /// Pending headers without a number are skipped.
+    ///
+    /// ```no_run
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ethers_providers::{Provider, Ws, StreamExt};
+    /// let provider = Provider::<Ws>::connect("ws://localhost:8545").await?;
+    /// let mut stream = provider.subscribe_block_number().await?;
+    /// while let Some(number) = stream.next().await {
+    ///     println!("new block {number}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_block_number(
+        &self,
+    ) -> Result<impl futures_util::Stream<Item = U64> + '_, ProviderError>
+    where
+        P: PubsubClient,
+    {
+        use futures_util::StreamExt;
+        let stream = self.subscribe_blocks().await?;
+        Ok(stream.filter_map(|block| futures_util::future::ready(block.number)))
+    }
+
+    /// Waits until the block with number `target` is available and returns it, polling
+    /// `eth_getBlockByNumber` every `poll_interval`.
+    ///
+    /// Returns [`ProviderError::BlockTimeout`] if the block is not available after `timeout`.
+    ///
+    /// ```no_run
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ethers_providers::{Http, Middleware, Provider};
+    /// use std::{convert::TryFrom, time::Duration};
+    /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
+    /// let next = provider.get_block_number().await? + 1;
+    /// let block = provider
+    ///     .wait_for_block(next, Duration::from_millis(100), Duration::from_secs(30))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_block(
+        &self,
+        target: U64,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Block<TxHash>, ProviderError> {
+        use futures_util::{future::Either, StreamExt};
+
+        let poll = async {
+            let mut ticks = crate::stream::interval(poll_interval);
+            loop {
+                if let Some(block) = self.get_block(target).await? {
+                    return Ok(block)
+                }
+                ticks.next().await;
+            }
+        };
+        futures_util::pin_mut!(poll);
+        let mut deadline = crate::stream::interval(timeout);
+        match futures_util::future::select(poll, deadline.next()).await {
+            Either::Left((res, _)) => res,
+            Either::Right(_) => Err(ProviderError::BlockTimeout(target)),
+        }
+    }
+
+    /// Streams the ERC-20 transfers of `token` sent or received by `account`.
+    ///
+    /// This installs two log filters, one for each side of the transfer, and merges their logs.
+    /// Transfers from `account` to itself are only yielded once.
+    ///
+    /// ```no_run
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ethers_core::types::Address;
+    /// use ethers_providers::{Http, Provider, StreamExt};
+    /// use std::convert::TryFrom;
+    /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
+    /// let weth = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".parse::<Address>()?;
+    /// let account = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse::<Address>()?;
+    ///
+    /// let mut transfers = provider.watch_transfers(weth, account).await?;
+    /// while let Some(transfer) = transfers.next().await {
+    ///     let transfer = transfer?;
+    ///     println!("{:?} -> {:?}: {}", transfer.from, transfer.to, transfer.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn watch_transfers(
+        &self,
+        token: Address,
+        account: Address,
+    ) -> Result<
+        impl futures_util::Stream<Item = Result<TransferEvent, ProviderError>> + '_,
+        ProviderError,
+    > {
+        use futures_util::{future, stream, StreamExt};
+
+        let filter = Filter::new().address(token).event(erc::ERC20_TRANSFER_EVENT);
+        let sent = self.watch(&filter.clone().topic1(account)).await?;
+        let received = self
+            .watch(&filter.topic2(account))
+            .await?
+            // self transfers are already yielded by the `sent` filter
+            .filter(move |log| future::ready(log.topics.get(1) != Some(&account.into())));
+
+        Ok(stream::select(sent, received)
+            .map(|log| TransferEvent::try_from(&log).map_err(ProviderError::CustomError)))
+    }
+
     #[cfg(test)]
     /// Anvil and Ganache-only function for mining empty blocks
     pub async fn mine(&self, num_blocks: usize) -> Result<(), ProviderError> {
@@ -1407,6 +1669,22 @@ impl Provider<crate::Ws> {
         Ok(Self::new(ws))
     }
 
+    /// Sets how often the websocket connection is pinged to detect that it went stale, see
+    /// [`Ws::ping_interval`](crate::Ws::ping_interval)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn ping_interval(self, interval: Duration) -> Self {
+        Self { inner: self.inner.ping_interval(interval), ..self }
+    }
+
+    /// Sets how long to wait for the pong after a ping, see
+    /// [`Ws::pong_timeout`](crate::Ws::pong_timeout)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn pong_timeout(self, timeout: Duration) -> Self {
+        Self { inner: self.inner.pong_timeout(timeout), ..self }
+    }
+
     /// Direct connection to a websocket endpoint
     #[cfg(target_arch = "wasm32")]
     pub async fn connect(url: &str) -> Result<Self, ProviderError> {
@@ -1685,9 +1963,9 @@ pub fn is_local_endpoint(url: &str) -> bool {
 /// ```
 #[cfg(feature = "dev-rpc")]
 pub mod dev_rpc {
-    use crate::{FromErr, Middleware, ProviderError};
+    use crate::{FromErr, JsonRpcError, Middleware, ProviderError};
     use async_trait::async_trait;
-    use ethers_core::types::U256;
+    use ethers_core::types::{Address, U256};
     use thiserror::Error;
 
     use std::fmt::Debug;
@@ -1716,6 +1994,13 @@ pub mod dev_rpc {
         fn inner(&self) -> &M {
             &self.0
         }
+
+        fn as_error_response(err: &Self::Error) -> Option<&JsonRpcError> {
+            match err {
+                DevRpcMiddlewareError::MiddlewareError(err) => M::as_error_response(err),
+                _ => None,
+            }
+        }
     }
 
     impl<M: Middleware> FromErr<M::Error> for DevRpcMiddlewareError<M> {
@@ -1755,6 +2040,52 @@ pub mod dev_rpc {
                 Err(DevRpcMiddlewareError::NoSnapshot)
             }
         }
+
+        /// Sets the balance of `account` to `value`, e.g. to fund a fresh wallet in a test.
+        ///
+        /// Uses `anvil_setBalance`, Hardhat supports the same method as `hardhat_setBalance`.
+        pub async fn fund_account(
+            &self,
+            account: Address,
+            value: U256,
+        ) -> Result<(), DevRpcMiddlewareError<M>> {
+            self.provider()
+                .request::<_, serde_json::Value>("anvil_setBalance", (account, value))
+                .await
+                .map_err(DevRpcMiddlewareError::ProviderError)?;
+            Ok(())
+        }
+
+        /// Resets the node to a fork of the chain served at `url`, at the given block.
+        ///
+        /// Uses `hardhat_reset`, which is supported by Hardhat and Anvil.
+        pub async fn fork(&self, url: &str, block: u64) -> Result<(), DevRpcMiddlewareError<M>> {
+            self.reset_fork(url, Some(block)).await
+        }
+
+        /// Resets the node to a fork of the chain served at `url`, at the given block or the
+        /// latest one if `None`. This can be used to change the fork target mid-test.
+        pub async fn reset_fork(
+            &self,
+            url: &str,
+            block: Option<u64>,
+        ) -> Result<(), DevRpcMiddlewareError<M>> {
+            let mut forking = serde_json::json!({ "jsonRpcUrl": url });
+            if let Some(block) = block {
+                forking["blockNumber"] = block.into();
+            }
+            // Hardhat responds with `true`, Anvil with `null`
+            self.provider()
+                .request::<_, serde_json::Value>(
+                    "hardhat_reset",
+                    [serde_json::json!({ "forking": forking })],
+                )
+                .await
+                .map_err(DevRpcMiddlewareError::ProviderError)?;
+            // the fork may be of another chain
+            self.provider().clear_cached_chain_id().await;
+            Ok(())
+        }
     }
     #[cfg(test)]
     // Celo blocks can not get parsed when used with Ganache
@@ -1819,6 +2150,75 @@ pub mod dev_rpc {
             assert_eq!(block, block0);
             assert_eq!(time, time0);
         }
+
+        #[tokio::test]
+        async fn test_reset_fork() {
+            let (provider, mock) = Provider::mocked();
+            let client = DevRpcMiddleware::new(provider);
+
+            mock.push(true).unwrap();
+            client.fork("http://localhost:8545", 15_000_000).await.unwrap();
+            mock.assert_request(
+                "hardhat_reset",
+                [serde_json::json!({
+                    "forking": { "jsonRpcUrl": "http://localhost:8545", "blockNumber": 15_000_000 }
+                })],
+            )
+            .unwrap();
+
+            mock.push(serde_json::Value::Null).unwrap();
+            client.reset_fork("http://localhost:8545", None).await.unwrap();
+            mock.assert_request(
+                "hardhat_reset",
+                [serde_json::json!({ "forking": { "jsonRpcUrl": "http://localhost:8545" } })],
+            )
+            .unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_reset_fork_clears_chain_id() {
+            let (provider, mock) = Provider::mocked();
+            mock.push(U256::one()).unwrap();
+            provider.cached_chain_id().await.unwrap();
+            mock.assert_request("eth_chainId", ()).unwrap();
+            let client = DevRpcMiddleware::new(provider.clone());
+
+            mock.push(serde_json::Value::Null).unwrap();
+            client.reset_fork("http://localhost:8545", None).await.unwrap();
+            mock.assert_request(
+                "hardhat_reset",
+                [serde_json::json!({ "forking": { "jsonRpcUrl": "http://localhost:8545" } })],
+            )
+            .unwrap();
+
+            // the clone outside the middleware requests the chain id of the fork
+            mock.push(U256::from(5u64)).unwrap();
+            assert_eq!(provider.cached_chain_id().await.unwrap(), 5);
+            mock.assert_request("eth_chainId", ()).unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_fund_account() {
+            let (provider, mock) = Provider::mocked();
+            let client = DevRpcMiddleware::new(provider);
+            let account = Address::repeat_byte(0x11);
+            let value = ethers_core::utils::parse_ether(100u64).unwrap();
+
+            mock.push(serde_json::Value::Null).unwrap();
+            client.fund_account(account, value).await.unwrap();
+            mock.assert_request("anvil_setBalance", (account, value)).unwrap();
+
+            // the accounts are fetched first, then their balances in order
+            let accounts = vec![account, Address::repeat_byte(0x22)];
+            mock.push(U256::from(2u64)).unwrap();
+            mock.push(value).unwrap();
+            mock.push::<Vec<Address>, _>(accounts.clone()).unwrap();
+            let balances = client.get_accounts_with_balances(None).await.unwrap();
+            assert_eq!(balances, vec![(accounts[0], value), (accounts[1], U256::from(2u64))]);
+            mock.assert_request("eth_accounts", ()).unwrap();
+            mock.assert_request("eth_getBalance", (accounts[0], "latest")).unwrap();
+            mock.assert_request("eth_getBalance", (accounts[1], "latest")).unwrap();
+        }
     }
 }
 
@@ -2023,6 +2423,199 @@ mod tests {
         assert_eq!(blocks, vec![1, 2, 3]);
     }
 
+    #[tokio::test]
+    #[cfg(not(feature = "celo"))]
+    async fn block_number_subscribe() {
+        use ethers_core::utils::Anvil;
+        use futures_util::StreamExt;
+        let anvil = Anvil::new().block_time(2u64).spawn();
+        let provider = Provider::connect(anvil.ws_endpoint()).await.unwrap();
+
+        let stream = provider.subscribe_block_number().await.unwrap();
+        let blocks = stream.take(2).map(|x| x.as_u64()).collect::<Vec<_>>().await;
+        assert_eq!(blocks, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn get_block_number_mocked() {
+        let (provider, mock) = Provider::mocked();
+        mock.push(U64::from(15_000_000u64)).unwrap();
+
+        let number = provider.get_block_number().await.unwrap();
+        mock.assert_request("eth_blockNumber", ()).unwrap();
+        assert!(number > U64::zero());
+        assert_eq!(number, U64::from(15_000_000u64));
+    }
+
+    #[tokio::test]
+    async fn wait_for_block_mocked() {
+        let (provider, mock) = Provider::mocked();
+        let target = U64::from(100u64);
+        let block = Block::<TxHash> { number: Some(target), ..Default::default() };
+        mock.push(block.clone()).unwrap();
+        mock.push(serde_json::Value::Null).unwrap();
+        mock.push(serde_json::Value::Null).unwrap();
+
+        let interval = Duration::from_millis(10);
+        let res = provider.wait_for_block(target, interval, Duration::from_secs(5)).await.unwrap();
+        assert_eq!(res, block);
+        mock.assert_request("eth_getBlockByNumber", ("0x64", false)).unwrap();
+
+        // the block never shows up
+        for _ in 0..100 {
+            mock.push(serde_json::Value::Null).unwrap();
+        }
+        let err = provider.wait_for_block(target, interval, Duration::from_millis(50)).await;
+        assert!(matches!(err, Err(ProviderError::BlockTimeout(number)) if number == target));
+    }
+
+    #[tokio::test]
+    async fn watch_transfers_mocked() {
+        use crate::{MockError, MockProvider};
+        use serde::{de::DeserializeOwned, Serialize};
+        use std::{collections::HashMap, sync::Mutex};
+
+        /// Answers `eth_getFilterChanges` with the logs of the polled filter, as a node would, and
+        /// delegates all other requests to the mock
+        #[derive(Debug)]
+        struct FilterChanges {
+            mock: MockProvider,
+            logs: Mutex<HashMap<U256, Vec<Log>>>,
+        }
+
+        #[async_trait]
+        impl JsonRpcClient for FilterChanges {
+            type Error = MockError;
+
+            async fn request<T: Debug + Serialize + Send + Sync, R: DeserializeOwned>(
+                &self,
+                method: &str,
+                params: T,
+            ) -> Result<R, MockError> {
+                if method != "eth_getFilterChanges" {
+                    return JsonRpcClient::request(&self.mock, method, params).await
+                }
+                let [id]: [U256; 1] = serde_json::from_value(serde_json::to_value(params)?)?;
+                let logs = self.logs.lock().unwrap().remove(&id).unwrap_or_default();
+                Ok(serde_json::from_value(serde_json::to_value(logs)?)?)
+            }
+        }
+
+        let token = Address::random();
+        let account = Address::random();
+        let other = Address::random();
+        let transfer = |from: Address, to: Address, value: u64| Log {
+            address: token,
+            topics: vec![
+                utils::keccak256(erc::ERC20_TRANSFER_EVENT).into(),
+                from.into(),
+                to.into(),
+            ],
+            data: ethers_core::abi::encode(&[ethers_core::abi::Token::Uint(value.into())]).into(),
+            block_number: Some(1u64.into()),
+            ..Default::default()
+        };
+
+        // a self transfer matches both filters
+        let self_transfer = transfer(account, account, 1);
+        let logs = HashMap::from([
+            (U256::from(1u64), vec![self_transfer.clone(), transfer(account, other, 2)]),
+            (U256::from(2u64), vec![self_transfer, transfer(other, account, 3)]),
+        ]);
+        let mock = MockProvider::new();
+        let client = FilterChanges { mock: mock.clone(), logs: Mutex::new(logs) };
+        let provider = Provider::new(client).interval(Duration::from_millis(10));
+        mock.push(U256::from(2u64)).unwrap();
+        mock.push(U256::from(1u64)).unwrap();
+
+        let transfers = provider.watch_transfers(token, account).await.unwrap();
+        futures_util::pin_mut!(transfers);
+        let mut values = Vec::new();
+        for _ in 0..3 {
+            values.push(transfers.next().await.unwrap().unwrap().value.as_u64());
+        }
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2, 3]);
+        // the self transfer is not yielded again
+        let next = tokio::time::timeout(Duration::from_millis(100), transfers.next()).await;
+        assert!(next.is_err());
+
+        let filter = Filter::new().address(token).event(erc::ERC20_TRANSFER_EVENT);
+        mock.assert_request("eth_newFilter", [filter.clone().topic1(account)]).unwrap();
+        mock.assert_request("eth_newFilter", [filter.topic2(account)]).unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_health_mocked() {
+        let (provider, mock) = Provider::mocked();
+        mock.push::<String, _>("Geth/v1.10.26-stable/linux-amd64/go1.19.3".to_string()).unwrap();
+        mock.push(U64::from(25u64)).unwrap();
+        mock.push(false).unwrap();
+
+        let health = provider.check_health().await.unwrap();
+        assert_eq!(health.syncing, SyncingStatus::IsFalse);
+        assert_eq!(health.peer_count, U64::from(25u64));
+        assert_eq!(health.client_version, "Geth/v1.10.26-stable/linux-amd64/go1.19.3");
+        assert!(health.is_healthy(25));
+        assert!(!health.is_healthy(26));
+
+        mock.assert_request("eth_syncing", ()).unwrap();
+        mock.assert_request("net_peerCount", ()).unwrap();
+        mock.assert_request("web3_clientVersion", ()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn is_syncing_mocked() {
+        let (provider, mock) = Provider::mocked();
+        mock.push(false).unwrap();
+        mock.push(serde_json::json!({
+            "currentBlock": "0xeaa2b4",
+            "highestBlock": "0xeaa329",
+            "startingBlock": "0xea97ee"
+        }))
+        .unwrap();
+
+        assert!(provider.is_syncing().await.unwrap());
+        mock.assert_request("eth_syncing", ()).unwrap();
+        assert!(!provider.is_syncing().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_bytecode_mocked() {
+        let (provider, mock) = Provider::mocked();
+        let address = Address::repeat_byte(0x11);
+        mock.push::<Bytes, _>(Bytes::default()).unwrap();
+        mock.push::<Bytes, _>(Bytes::from(vec![0x60, 0x00, 0xf4])).unwrap();
+
+        let code = provider.get_bytecode(address, None).await.unwrap();
+        mock.assert_request("eth_getCode", (address, "latest")).unwrap();
+        assert!(!code.is_empty());
+        assert!(code.contains_delegatecall());
+
+        let code = provider.get_bytecode(address, None).await.unwrap();
+        assert!(code.is_empty());
+    }
+
+    #[tokio::test]
+    async fn send_signed_transaction_mocked() {
+        let (provider, mock) = Provider::mocked();
+        let tx: TypedTransaction = TransactionRequest::new()
+            .to(Address::repeat_byte(0x11))
+            .value(1u64)
+            .nonce(0u64)
+            .gas(21_000u64)
+            .gas_price(1u64)
+            .chain_id(1u64)
+            .into();
+        let signature = Signature { r: 1u64.into(), s: 2u64.into(), v: 37 };
+        let hash = H256::repeat_byte(0x22);
+        mock.push(hash).unwrap();
+
+        let pending = provider.send_signed_transaction(&tx, &signature).await.unwrap();
+        assert_eq!(pending.tx_hash(), hash);
+        mock.assert_request("eth_sendRawTransaction", [tx.rlp_signed(&signature)]).unwrap();
+    }
+
     #[tokio::test]
     #[cfg_attr(feature = "celo", ignore)]
     async fn fee_history() {
@@ -2080,6 +2673,9 @@ mod tests {
     #[tokio::test]
     async fn test_fill_transaction_1559() {
         let (mut provider, mock) = Provider::mocked();
+        // the chain id is requested by the first fill only
+        mock.push(U256::one()).unwrap();
+        provider.cached_chain_id().await.unwrap();
         provider.from = Some("0x6fC21092DA55B392b045eD78F4732bff3C580e2c".parse().unwrap());
 
         let gas = U256::from(21000_usize);
@@ -2150,9 +2746,170 @@ mod tests {
         assert!(matches!(res, Err(ProviderError::JsonRpcClientError(_))));
     }
 
+    #[tokio::test]
+    async fn test_fill_transaction_chain_id() {
+        let (provider, mock) = Provider::mocked();
+        let tx = TransactionRequest::new().gas(21000u64).gas_price(1u64);
+
+        mock.push(U256::from(5u64)).unwrap();
+        let mut filled = tx.clone().into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+        assert_eq!(filled.chain_id(), Some(5u64.into()));
+        mock.assert_request("eth_chainId", ()).unwrap();
+
+        // the chain id is cached and an explicit one is kept
+        let mut filled = tx.clone().into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+        assert_eq!(filled.chain_id(), Some(5u64.into()));
+        let mut filled = tx.clone().chain_id(1u64).into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+        assert_eq!(filled.chain_id(), Some(1u64.into()));
+        mock.assert_request("eth_chainId", ()).unwrap_err();
+
+        // a chain id that does not fit into a transaction is an error, not a panic
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::MAX).unwrap();
+        let err = provider.fill_transaction(&mut tx.into(), None).await.unwrap_err();
+        assert!(matches!(err, ProviderError::CustomError(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_fill_transaction_reverting_gas_estimate() {
+        let (provider, mock) = Provider::mocked();
+        // the chain id is requested by the first fill only
+        mock.push(U256::one()).unwrap();
+        provider.cached_chain_id().await.unwrap();
+        let tx = TransactionRequest::new().gas_price(1u64).data(vec![1, 2, 3, 4]);
+
+        // a revert during gas estimation carries the revert data
+        let data: Bytes = "0x08c379a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000a6e6f7420616c6c6f776564000000000000000000000000000000000000000000".parse().unwrap();
+        mock.push_error(JsonRpcError {
+            code: -32000,
+            message: "execution reverted".to_string(),
+            data: Some(serde_json::json!(data)),
+        });
+        let err = provider.fill_transaction(&mut tx.clone().into(), None).await.unwrap_err();
+        match err {
+            ProviderError::GasEstimationFailed(revert) => {
+                assert_eq!(revert.message, "execution reverted");
+                assert_eq!(revert.data, Some(data));
+            }
+            err => panic!("expected GasEstimationFailed, got {err:?}"),
+        }
+
+        // other error responses are passed through
+        mock.push_error(JsonRpcError {
+            code: -32000,
+            message: "insufficient funds for gas * price + value".to_string(),
+            data: None,
+        });
+        let err = provider.fill_transaction(&mut tx.into(), None).await.unwrap_err();
+        assert!(err.as_error_response().unwrap().is_insufficient_funds());
+    }
+
+    #[tokio::test]
+    async fn test_fill_transaction_pre_london() {
+        let (provider, mock) = Provider::mocked();
+        // the chain id is requested by the first fill only
+        mock.push(U256::one()).unwrap();
+        provider.cached_chain_id().await.unwrap();
+        mock.assert_request("eth_chainId", ()).unwrap();
+
+        let gas_price = U256::from(50_usize);
+        // a block without a base fee
+        let block = serde_json::json!({ "number": "0x1", "hash": H256::zero() });
+        let tx = Eip1559TransactionRequest::new().gas(21000u64);
+
+        mock.push(gas_price).unwrap();
+        mock.push(block.clone()).unwrap();
+        let mut filled = tx.clone().into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+
+        assert!(matches!(filled, TypedTransaction::Legacy(_)));
+        assert_eq!(filled.gas_price(), Some(gas_price));
+        mock.assert_request("eth_getBlockByNumber", ("latest", false)).unwrap();
+        mock.assert_request("eth_gasPrice", ()).unwrap();
+
+        // the access list is kept
+        let access_list: AccessList = vec![Default::default()].into();
+        mock.push(gas_price).unwrap();
+        mock.push(block.clone()).unwrap();
+        let mut filled = tx.clone().access_list(access_list.clone()).into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+
+        match filled {
+            TypedTransaction::Eip2930(inner) => {
+                assert_eq!(inner.access_list, access_list);
+                assert_eq!(inner.tx.gas_price, Some(gas_price));
+            }
+            tx => panic!("expected an eip2930 transaction, got {tx:?}"),
+        }
+
+        // a max fee set by the user becomes the gas price
+        mock.push(block.clone()).unwrap();
+        let max_fee = U256::from(70_usize);
+        let mut filled = tx.clone().max_fee_per_gas(max_fee).into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+        assert!(matches!(filled, TypedTransaction::Legacy(_)));
+        assert_eq!(filled.gas_price(), Some(max_fee));
+
+        // a tip alone is not silently dropped
+        mock.push(block).unwrap();
+        let mut filled = tx.max_priority_fee_per_gas(2u64).into();
+        let err = provider.fill_transaction(&mut filled, None).await.unwrap_err();
+        assert!(matches!(err, ProviderError::CustomError(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_fill_transaction_london() {
+        let (provider, mock) = Provider::mocked();
+        mock.push(U256::one()).unwrap();
+        provider.cached_chain_id().await.unwrap();
+        mock.assert_request("eth_chainId", ()).unwrap();
+
+        let block = serde_json::json!({
+            "number": "0x1",
+            "hash": H256::zero(),
+            "baseFeePerGas": "0x64"
+        });
+        let fee_history = serde_json::json!({
+            "oldestBlock": "0x1",
+            "baseFeePerGas": ["0x64", "0x64"],
+            "gasUsedRatio": [0.5],
+            "reward": [["0x1"]]
+        });
+        mock.push(fee_history).unwrap();
+        mock.push(block).unwrap();
+        let mut filled = Eip1559TransactionRequest::new().gas(21000u64).into();
+        provider.fill_transaction(&mut filled, None).await.unwrap();
+
+        match filled {
+            TypedTransaction::Eip1559(inner) => {
+                assert!(inner.max_fee_per_gas.is_some());
+                assert!(inner.max_priority_fee_per_gas.is_some());
+            }
+            tx => panic!("expected an eip1559 transaction, got {tx:?}"),
+        }
+        // the latest block is fetched once, for both the check and the estimate
+        mock.assert_request("eth_getBlockByNumber", ("latest", false)).unwrap();
+        mock.assert_request(
+            "eth_feeHistory",
+            (
+                utils::serialize(&U256::from(utils::EIP1559_FEE_ESTIMATION_PAST_BLOCKS)),
+                "latest",
+                [utils::EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE],
+            ),
+        )
+        .unwrap();
+        mock.assert_request("eth_getBlockByNumber", ("latest", false)).unwrap_err();
+    }
+
     #[tokio::test]
     async fn test_fill_transaction_legacy() {
         let (mut provider, mock) = Provider::mocked();
+        // the chain id is requested by the first fill only
+        mock.push(U256::one()).unwrap();
+        provider.cached_chain_id().await.unwrap();
         provider.from = Some("0x6fC21092DA55B392b045eD78F4732bff3C580e2c".parse().unwrap());
 
         let gas = U256::from(21000_usize);
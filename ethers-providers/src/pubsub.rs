@@ -1,4 +1,4 @@
-use crate::{JsonRpcClient, Middleware, Provider, TransactionStream};
+use crate::{JsonRpcClient, Provider, TransactionStream};
 
 use ethers_core::types::{TxHash, U256};
 
@@ -22,7 +22,7 @@ pub trait PubsubClient: JsonRpcClient {
     /// Add a subscription to this transport
     fn subscribe<T: Into<U256>>(&self, id: T) -> Result<Self::NotificationStream, Self::Error>;
 
-    /// Remove a subscription from this transport
+    /// Remove a subscription from this transport and cancel it on the node
     fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), Self::Error>;
 }
 
@@ -41,6 +41,9 @@ pub struct SubscriptionStream<'a, P: PubsubClient, R: DeserializeOwned> {
     rx: P::NotificationStream,
 
     ret: PhantomData<R>,
+
+    /// Whether [`SubscriptionStream::unsubscribe`] already cancelled the subscription
+    unsubscribed: bool,
 }
 
 impl<'a, P, R> SubscriptionStream<'a, P, R>
@@ -58,12 +61,23 @@ where
     pub fn new(id: U256, provider: &'a Provider<P>) -> Result<Self, P::Error> {
         // Call the underlying PubsubClient's subscribe
         let rx = provider.as_ref().subscribe(id)?;
-        Ok(Self { id, provider, rx, ret: PhantomData, loaded_elements: VecDeque::new() })
+        Ok(Self {
+            id,
+            provider,
+            rx,
+            ret: PhantomData,
+            loaded_elements: VecDeque::new(),
+            unsubscribed: false,
+        })
     }
 
-    /// Unsubscribes from the subscription.
-    pub async fn unsubscribe(&self) -> Result<bool, crate::ProviderError> {
-        self.provider.unsubscribe(self.id).await
+    /// Unsubscribes from the subscription, i.e. removes it from the transport and cancels it on
+    /// the node.
+    ///
+    /// Dropping the stream does the same, this only surfaces the error of the transport.
+    pub fn unsubscribe(mut self) -> Result<(), P::Error> {
+        self.unsubscribed = true;
+        self.provider.as_ref().unsubscribe(self.id)
     }
 
     pub fn set_loaded_elements(&mut self, loaded_elements: VecDeque<R>) {
@@ -87,16 +101,16 @@ where
             return Poll::Ready(next_element)
         }
 
-        let this = self.project();
-        match futures_util::ready!(this.rx.poll_next(ctx)) {
-            Some(item) => match serde_json::from_str(item.get()) {
-                Ok(res) => Poll::Ready(Some(res)),
-                Err(err) => {
-                    error!("failed to deserialize item {:?}", err);
-                    Poll::Pending
-                }
-            },
-            None => Poll::Ready(None),
+        let mut this = self.project();
+        loop {
+            match futures_util::ready!(this.rx.as_mut().poll_next(ctx)) {
+                Some(item) => match serde_json::from_str(item.get()) {
+                    Ok(res) => return Poll::Ready(Some(res)),
+                    // skip the item, the next one may be valid
+                    Err(err) => error!("failed to deserialize item {:?}", err),
+                },
+                None => return Poll::Ready(None),
+            }
         }
     }
 }
@@ -108,10 +122,11 @@ where
     R: DeserializeOwned,
 {
     fn drop(self: Pin<&mut Self>) {
-        // on drop it removes the handler from the websocket so that it stops
-        // getting populated. We need to call `unsubscribe` explicitly to cancel
-        // the subscription
-        let _ = (*self.provider).as_ref().unsubscribe(self.id);
+        // on drop it removes the handler from the transport so that it stops
+        // getting populated, and cancels the subscription on the node
+        if !self.unsubscribed {
+            let _ = (*self.provider).as_ref().unsubscribe(self.id);
+        }
     }
 }
 
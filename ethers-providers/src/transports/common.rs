@@ -1,5 +1,5 @@
 // Code adapted from: https://github.com/althea-net/guac_rs/tree/master/web3/src/jsonrpc
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use serde::{
     de::{self, MapAccess, Unexpected, Visitor},
@@ -8,7 +8,7 @@ use serde::{
 use serde_json::{value::RawValue, Value};
 use thiserror::Error;
 
-use ethers_core::types::U256;
+use ethers_core::types::{Bytes, U256};
 
 #[derive(Deserialize, Debug, Clone, Error)]
 /// A JSON-RPC 2.0 error
@@ -21,6 +21,39 @@ pub struct JsonRpcError {
     pub data: Option<Value>,
 }
 
+impl JsonRpcError {
+    /// Returns true if the transaction was rejected because its nonce was already used
+    pub fn is_nonce_too_low(&self) -> bool {
+        let message = self.message.to_lowercase();
+        message.contains("nonce too low") || message.contains("nonce is too low")
+    }
+
+    /// Returns true if the sender can not pay for the transaction's gas and value
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.message.to_lowercase().contains("insufficient funds")
+    }
+
+    /// Returns true if the call or transaction reverted.
+    ///
+    /// Geth reports reverts with error code `3`, other nodes only mention the revert in the
+    /// message.
+    pub fn is_revert(&self) -> bool {
+        self.code == 3 || self.message.to_lowercase().contains("revert")
+    }
+
+    /// Returns the revert data of a reverted call or transaction, if the node included it.
+    ///
+    /// The data is either the `data` field itself, or nested in a `data` object.
+    pub fn revert_data(&self) -> Option<Bytes> {
+        if !self.is_revert() {
+            return None
+        }
+        let data = self.data.as_ref()?;
+        let hex = data.as_str().or_else(|| data.get("data")?.as_str())?;
+        Bytes::from_str(hex).ok()
+    }
+}
+
 impl fmt::Display for JsonRpcError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(code: {}, message: {}, data: {:?})", self.code, self.message, self.data)
@@ -216,6 +249,48 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn json_rpc_error_kinds() {
+        let err: JsonRpcError = serde_json::from_str(
+            r#"{"code":-32000,"message":"nonce too low: address 0x0, tx: 1 state: 2"}"#,
+        )
+        .unwrap();
+        assert!(err.is_nonce_too_low());
+        assert!(!err.is_insufficient_funds());
+        assert!(!err.is_revert());
+
+        let err: JsonRpcError = serde_json::from_str(
+            r#"{"code":-32000,"message":"insufficient funds for gas * price + value"}"#,
+        )
+        .unwrap();
+        assert!(err.is_insufficient_funds());
+        assert!(!err.is_nonce_too_low());
+        assert_eq!(err.revert_data(), None);
+    }
+
+    #[test]
+    fn json_rpc_error_revert_data() {
+        // geth
+        let err: JsonRpcError = serde_json::from_str(
+            r#"{"code":3,"message":"execution reverted","data":"0x08c379a0"}"#,
+        )
+        .unwrap();
+        assert!(err.is_revert());
+        assert_eq!(err.revert_data(), Some(Bytes::from(vec![0x08, 0xc3, 0x79, 0xa0])));
+
+        // nested in a data object
+        let err: JsonRpcError = serde_json::from_str(
+            r#"{"code":-32603,"message":"VM Exception while processing transaction: revert","data":{"data":"0xdeadbeef"}}"#,
+        )
+        .unwrap();
+        assert!(err.is_revert());
+        assert_eq!(err.revert_data(), Some(Bytes::from(vec![0xde, 0xad, 0xbe, 0xef])));
+
+        let err: JsonRpcError =
+            serde_json::from_str(r#"{"code":3,"message":"execution reverted"}"#).unwrap();
+        assert_eq!(err.revert_data(), None);
+    }
+
     #[test]
     fn deser_response() {
         let _ =
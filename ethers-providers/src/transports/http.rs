@@ -2,7 +2,10 @@
 use crate::{provider::ProviderError, JsonRpcClient};
 
 use async_trait::async_trait;
-use reqwest::{header::HeaderValue, Client, Error as ReqwestError};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client, Error as ReqwestError,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     str::FromStr,
@@ -42,18 +45,28 @@ pub enum ClientError {
     #[error(transparent)]
     ReqwestError(#[from] ReqwestError),
     #[error(transparent)]
-    /// Thrown if the response could not be parsed
+    /// Thrown if the node responded with a JSON-RPC error, e.g. because the method does not exist
     JsonRpcError(#[from] JsonRpcError),
 
     #[error("Deserialization Error: {err}. Response: {text}")]
-    /// Serde JSON Error
+    /// Thrown if the response could not be parsed, i.e. it is malformed JSON or does not match
+    /// the expected type
     SerdeJson { err: serde_json::Error, text: String },
 }
 
+impl ClientError {
+    /// Returns true if the node's response could not be parsed, as opposed to the node returning
+    /// a JSON-RPC error or the request failing
+    pub fn is_serde_error(&self) -> bool {
+        matches!(self, ClientError::SerdeJson { .. })
+    }
+}
+
 impl From<ClientError> for ProviderError {
     fn from(src: ClientError) -> Self {
         match src {
             ClientError::ReqwestError(err) => ProviderError::HTTPError(err),
+            ClientError::JsonRpcError(err) => ProviderError::JsonRpcError(err),
             _ => ProviderError::JsonRpcClientError(Box::new(src)),
         }
     }
@@ -146,9 +159,33 @@ impl Provider {
         let mut auth_value = HeaderValue::from_str(&auth.to_string())?;
         auth_value.set_sensitive(true);
 
-        let mut headers = reqwest::header::HeaderMap::new();
+        let mut headers = HeaderMap::new();
         headers.insert(reqwest::header::AUTHORIZATION, auth_value);
 
+        Self::new_with_headers(url, headers)
+    }
+
+    /// Initializes a new HTTP Client which sends the given headers with every request, e.g. for
+    /// nodes that expect an API key in a custom header.
+    ///
+    /// API keys that are part of the url can be passed to [`Provider::new`] as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers_providers::Http;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    /// use url::Url;
+    ///
+    /// let url = Url::parse("http://localhost:8545").unwrap();
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-api-key", HeaderValue::from_static("my-api-key"));
+    /// let provider = Http::new_with_headers(url, headers).unwrap();
+    /// ```
+    pub fn new_with_headers(
+        url: impl Into<Url>,
+        headers: HeaderMap,
+    ) -> Result<Self, HttpClientError> {
         let client = Client::builder().default_headers(headers).build()?;
 
         Ok(Self::new_with_client(url, client))
@@ -197,3 +234,90 @@ pub enum HttpClientError {
     #[error(transparent)]
     ClientBuild(#[from] reqwest::Error),
 }
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use ethers_core::types::U64;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves a single `eth_blockNumber` response and returns the lowercased request headers
+    fn serve_once() -> (Url, mpsc::Receiver<Vec<String>>) {
+        serve_once_with(r#"{"jsonrpc":"2.0","id":1,"result":"0x2a"}"#)
+    }
+
+    /// Serves a single response with the given body and returns the lowercased request headers
+    fn serve_once_with(body: &'static str) -> (Url, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_lowercase();
+                if line.is_empty() {
+                    break
+                }
+                headers.push(line);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            tx.send(headers).unwrap();
+        });
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn sends_auth_header() {
+        let (url, headers) = serve_once();
+        let provider = Provider::new_with_auth(url, Authorization::bearer("token")).unwrap();
+
+        let block: U64 = provider.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(block, 42.into());
+        assert!(headers.recv().unwrap().contains(&"authorization: bearer token".to_string()));
+    }
+
+    #[tokio::test]
+    async fn sends_custom_headers() {
+        let (url, headers) = serve_once();
+        let mut custom = HeaderMap::new();
+        custom.insert("x-api-key", HeaderValue::from_static("secret"));
+        let provider = Provider::new_with_headers(url, custom).unwrap();
+
+        let block: U64 = provider.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(block, 42.into());
+        assert!(headers.recv().unwrap().contains(&"x-api-key: secret".to_string()));
+    }
+
+    #[tokio::test]
+    async fn distinguishes_malformed_responses_from_rpc_errors() {
+        let (url, _) = serve_once_with(r#"{"jsonrpc":"2.0","id":1,"result":"0x2a""#);
+        let err = Provider::new(url).request::<_, U64>("eth_blockNumber", ()).await.unwrap_err();
+        assert!(err.is_serde_error());
+        assert!(
+            matches!(err, ClientError::SerdeJson { ref text, .. } if text.ends_with("\"0x2a\""))
+        );
+
+        let (url, _) = serve_once_with(
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"method not found"}}"#,
+        );
+        let err = Provider::new(url).request::<_, U64>("eth_foo", ()).await.unwrap_err();
+        assert!(!err.is_serde_error());
+        assert!(matches!(err, ClientError::JsonRpcError(JsonRpcError { code: -32601, .. })));
+    }
+}
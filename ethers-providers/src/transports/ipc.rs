@@ -117,7 +117,18 @@ impl PubsubClient for Ipc {
     }
 
     fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), IpcError> {
-        self.send(TransportMessage::Unsubscribe { id: id.into() })
+        let id = id.into();
+        self.send(TransportMessage::Unsubscribe { id })?;
+
+        // also cancel the subscription on the node, without waiting for its response
+        let next_id = self.id.fetch_add(1, Ordering::SeqCst);
+        let (sender, _) = oneshot::channel();
+        self.send(TransportMessage::Request {
+            id: next_id,
+            request: serde_json::to_vec(&Request::new(next_id, "eth_unsubscribe", [id]))?
+                .into_boxed_slice(),
+            sender,
+        })
     }
 }
 
@@ -316,7 +327,10 @@ pub enum IpcError {
 
 impl From<IpcError> for ProviderError {
     fn from(src: IpcError) -> Self {
-        ProviderError::JsonRpcClientError(Box::new(src))
+        match src {
+            IpcError::JsonRpcError(err) => ProviderError::JsonRpcError(err),
+            _ => ProviderError::JsonRpcClientError(Box::new(src)),
+        }
     }
 }
 #[cfg(all(test, target_family = "unix"))]
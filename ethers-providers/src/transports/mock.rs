@@ -1,4 +1,4 @@
-use crate::{JsonRpcClient, ProviderError};
+use crate::{JsonRpcClient, JsonRpcError, ProviderError};
 
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
@@ -23,7 +23,7 @@ enum MockParams {
 /// Mock transport used in test environments.
 pub struct MockProvider {
     requests: Arc<Mutex<VecDeque<(String, MockParams)>>>,
-    responses: Arc<Mutex<VecDeque<Value>>>,
+    responses: Arc<Mutex<VecDeque<Result<Value, JsonRpcError>>>>,
 }
 
 impl Default for MockProvider {
@@ -51,7 +51,7 @@ impl JsonRpcClient for MockProvider {
         };
         self.requests.lock().unwrap().push_back((method.to_owned(), params));
         let mut data = self.responses.lock().unwrap();
-        let element = data.pop_back().ok_or(MockError::EmptyResponses)?;
+        let element = data.pop_back().ok_or(MockError::EmptyResponses)??;
         let res: R = serde_json::from_value(element)?;
 
         Ok(res)
@@ -90,9 +90,14 @@ impl MockProvider {
     /// Pushes the data to the responses
     pub fn push<T: Serialize + Send + Sync, K: Borrow<T>>(&self, data: K) -> Result<(), MockError> {
         let value = serde_json::to_value(data.borrow())?;
-        self.responses.lock().unwrap().push_back(value);
+        self.responses.lock().unwrap().push_back(Ok(value));
         Ok(())
     }
+
+    /// Pushes an error response, returned as [`MockError::JsonRpcError`] by the matching request
+    pub fn push_error(&self, error: JsonRpcError) {
+        self.responses.lock().unwrap().push_back(Err(error));
+    }
 }
 
 #[derive(Error, Debug)]
@@ -106,11 +111,18 @@ pub enum MockError {
 
     #[error("empty responses array, please push some responses")]
     EmptyResponses,
+
+    /// An error response pushed via [`MockProvider::push_error`]
+    #[error(transparent)]
+    JsonRpcError(#[from] JsonRpcError),
 }
 
 impl From<MockError> for ProviderError {
     fn from(src: MockError) -> Self {
-        ProviderError::JsonRpcClientError(Box::new(src))
+        match src {
+            MockError::JsonRpcError(err) => ProviderError::JsonRpcError(err),
+            src => ProviderError::JsonRpcClientError(Box::new(src)),
+        }
     }
 }
 
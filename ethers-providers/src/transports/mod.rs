@@ -1,5 +1,5 @@
 mod common;
-pub use common::Authorization;
+pub use common::{Authorization, JsonRpcError};
 
 // only used with WS
 #[cfg(feature = "ws")]
@@ -41,5 +41,8 @@ pub use rw::{RwClient, RwClientError};
 mod retry;
 pub use retry::*;
 
+mod rate_limit;
+pub use rate_limit::RateLimitClient;
+
 mod mock;
 pub use mock::{MockError, MockProvider};
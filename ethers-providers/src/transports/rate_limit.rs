@@ -0,0 +1,191 @@
+//! A [JsonRpcClient] implementation that limits the rate of requests sent to the inner client
+
+use crate::JsonRpcClient;
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, fmt::Debug, sync::Mutex, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use wasm_timer::Instant;
+
+/// [RateLimitClient] presents as a wrapper around [JsonRpcClient] that enforces a maximum number
+/// of requests per second, e.g. to stay within the limits of free-tier node providers.
+///
+/// Instead of failing, requests exceeding the limit wait until they can be sent. The limit is
+/// enforced with a token bucket, so bursts of up to `requests_per_second` requests are sent right
+/// away.
+///
+/// Individual methods can be limited further with [RateLimitClient::with_method_limit], requests
+/// for those methods count towards both limits.
+///
+/// # Example
+///
+/// ```
+/// use ethers_providers::{Http, Provider, RateLimitClient};
+/// use url::Url;
+///
+/// let http = Http::new(Url::parse("http://localhost:8545").unwrap());
+/// let client = RateLimitClient::new(http, 25).with_method_limit("eth_getLogs", 5);
+/// let provider = Provider::new(client);
+/// ```
+#[derive(Debug)]
+pub struct RateLimitClient<T> {
+    inner: T,
+    limit: Mutex<TokenBucket>,
+    method_limits: HashMap<String, Mutex<TokenBucket>>,
+}
+
+impl<T> RateLimitClient<T> {
+    /// Creates a new `RateLimitClient` that sends at most `requests_per_second` requests per
+    /// second to `inner`.
+    ///
+    /// # Panics
+    ///
+    /// If `requests_per_second` is zero.
+    pub fn new(inner: T, requests_per_second: u32) -> Self {
+        Self {
+            inner,
+            limit: Mutex::new(TokenBucket::new(requests_per_second)),
+            method_limits: HashMap::new(),
+        }
+    }
+
+    /// Limits the requests for `method` to `requests_per_second`.
+    ///
+    /// # Panics
+    ///
+    /// If `requests_per_second` is zero.
+    #[must_use]
+    pub fn with_method_limit(
+        mut self,
+        method: impl Into<String>,
+        requests_per_second: u32,
+    ) -> Self {
+        self.method_limits.insert(method.into(), Mutex::new(TokenBucket::new(requests_per_second)));
+        self
+    }
+
+    /// Returns the wrapped client
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns how long a request for `method` has to wait before it can be sent, taking a token
+    /// from every bucket that applies.
+    fn reserve(&self, method: &str) -> Duration {
+        let now = Instant::now();
+        let wait = self.limit.lock().unwrap().reserve(now);
+        match self.method_limits.get(method) {
+            Some(limit) => wait.max(limit.lock().unwrap().reserve(now)),
+            None => wait,
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> JsonRpcClient for RateLimitClient<T>
+where
+    T: JsonRpcClient,
+{
+    type Error = T::Error;
+
+    async fn request<A, R>(&self, method: &str, params: A) -> Result<R, Self::Error>
+    where
+        A: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let wait = self.reserve(method);
+        if !wait.is_zero() {
+            tracing::trace!(method, ?wait, "rate limited, delaying request");
+
+            // if the timer fails the request is sent right away, the node enforces its own limit
+            #[cfg(target_arch = "wasm32")]
+            let _ = wasm_timer::Delay::new(wait).await;
+
+            #[cfg(not(target_arch = "wasm32"))]
+            tokio::time::sleep(wait).await;
+        }
+        self.inner.request(method, params).await
+    }
+}
+
+/// A token bucket that is refilled continuously at `rate` tokens per second and holds at most
+/// `rate` tokens.
+///
+/// Tokens are reserved in advance, i.e. the balance becomes negative while requests are waiting
+/// for their turn.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests_per_second: u32) -> Self {
+        assert!(requests_per_second > 0, "rate limit must be at least one request per second");
+        let rate = requests_per_second as f64;
+        Self { rate, tokens: rate, last_refill: Instant::now() }
+    }
+
+    /// Takes a token and returns how long to wait until it is available.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.last_refill = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::{MockProvider, Provider};
+    use ethers_core::types::U64;
+    use futures_util::future::join_all;
+
+    #[tokio::test]
+    async fn delays_bursts_exceeding_the_limit() {
+        let mock = MockProvider::new();
+        for _ in 0..10 {
+            mock.push(U64::from(1u64)).unwrap();
+        }
+        let provider = Provider::new(RateLimitClient::new(mock, 2));
+
+        let start = Instant::now();
+        let results =
+            join_all((0..10).map(|_| provider.request::<_, U64>("eth_blockNumber", ()))).await;
+        assert!(results.iter().all(Result::is_ok));
+        // 2 requests are sent right away, the other 8 at 2 per second
+        assert!(start.elapsed() >= Duration::from_secs(4), "{:?}", start.elapsed());
+    }
+
+    #[tokio::test]
+    async fn limits_methods_separately() {
+        let mock = MockProvider::new();
+        for _ in 0..4 {
+            mock.push(U64::from(1u64)).unwrap();
+        }
+        let provider =
+            Provider::new(RateLimitClient::new(mock, 100).with_method_limit("eth_getLogs", 1));
+
+        let start = Instant::now();
+        provider.request::<_, U64>("eth_blockNumber", ()).await.unwrap();
+        provider.request::<_, U64>("eth_blockNumber", ()).await.unwrap();
+        provider.request::<_, U64>("eth_getLogs", ()).await.unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        provider.request::<_, U64>("eth_getLogs", ()).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+}
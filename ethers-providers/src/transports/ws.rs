@@ -16,10 +16,13 @@ use serde_json::value::RawValue;
 use std::{
     collections::{btree_map::Entry, BTreeMap},
     fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
+    time::Duration,
 };
 use thiserror::Error;
 use tracing::trace;
@@ -64,6 +67,7 @@ if_not_wasm! {
     type WsError = tungstenite::Error;
     type WsStreamItem = Result<Message, WsError>;
     use super::Authorization;
+    use futures_util::future::FutureExt;
     use tracing::{debug, error, warn};
     use http::Request as HttpRequest;
     use tungstenite::client::IntoClientRequest;
@@ -71,15 +75,33 @@ if_not_wasm! {
 
 type Pending = oneshot::Sender<Result<Box<RawValue>, JsonRpcError>>;
 type Subscription = mpsc::UnboundedSender<Box<RawValue>>;
+type ConnectFuture<S> = Pin<Box<dyn Future<Output = Result<S, ClientError>> + Send>>;
+
+/// The delay between two failed attempts to reconnect
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// The default interval between two pings, see [`Ws::ping_interval`]
+const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The default time to wait for a pong, see [`Ws::pong_timeout`]
+const DEFAULT_PONG_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Instructions for the `WsServer`.
 enum Instruction {
-    /// JSON-RPC request
-    Request { id: u64, request: String, sender: Pending },
+    /// JSON-RPC request, `subscription_params` are the params of an `eth_subscribe` request
+    Request {
+        id: u64,
+        request: String,
+        sender: Pending,
+        subscription_params: Option<Box<RawValue>>,
+    },
     /// Create a new subscription
     Subscribe { id: U256, sink: Subscription },
     /// Cancel an existing subscription
     Unsubscribe { id: U256 },
+    /// Change the ping interval or the pong timeout
+    #[cfg_attr(target_arch = "wasm32", allow(unused))]
+    Keepalive { ping_interval: Option<Duration>, pong_timeout: Option<Duration> },
 }
 
 /// A JSON-RPC Client over Websockets.
@@ -111,11 +133,12 @@ impl Ws {
     where
         S: Send + Sync + Stream<Item = WsStreamItem> + Sink<Message, Error = WsError> + Unpin,
     {
+        let id = Arc::new(AtomicU64::new(1));
         let (sink, stream) = mpsc::unbounded();
         // Spawn the server
-        WsServer::new(ws, stream).spawn();
+        WsServer::new(ws, stream, id.clone()).spawn();
 
-        Self { id: Arc::new(AtomicU64::new(1)), instructions: sink }
+        Self { id, instructions: sink }
     }
 
     /// Returns true if the WS connection is active, false otherwise
@@ -138,6 +161,50 @@ impl Ws {
         Ok(Self::new(ws))
     }
 
+    /// Initializes a new WebSocket Client that reconnects if the connection drops, e.g. because
+    /// the node restarted.
+    ///
+    /// After a reconnect all active subscriptions are registered again, their streams continue
+    /// with the notifications of the new connection. Notifications sent while disconnected are
+    /// lost and requests that were in flight fail with [`ClientError::Canceled`]. If all
+    /// `max_attempts` to reconnect fail, the client shuts down like a client without reconnects.
+    ///
+    /// ```no_run
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ethers_providers::Ws;
+    ///
+    /// let ws = Ws::connect_with_reconnects("wss://localhost:8545", 5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn connect_with_reconnects(
+        url: impl IntoClientRequest + Unpin,
+        max_attempts: usize,
+    ) -> Result<Self, ClientError> {
+        let request = url.into_client_request()?;
+        let (uri, headers) = (request.uri().clone(), request.headers().clone());
+        let (ws, _) = connect_async(request).await?;
+
+        let connect = move || {
+            let (uri, headers) = (uri.clone(), headers.clone());
+            async move {
+                let mut request = uri.into_client_request()?;
+                *request.headers_mut() = headers;
+                let (ws, _) = connect_async(request).await?;
+                Ok(ws)
+            }
+        };
+
+        let id = Arc::new(AtomicU64::new(1));
+        let (sink, stream) = mpsc::unbounded();
+        WsServer::new(ws, stream, id.clone())
+            .with_reconnect(Reconnect::new(connect, max_attempts))
+            .spawn();
+
+        Ok(Self { id, instructions: sink })
+    }
+
     /// Initializes a new WebSocket Client with authentication
     #[cfg(not(target_arch = "wasm32"))]
     pub async fn connect_with_auth(
@@ -153,6 +220,31 @@ impl Ws {
         Self::connect(request).await
     }
 
+    /// Sets how often a ping frame is sent to detect stale connections, every 30 seconds by
+    /// default.
+    ///
+    /// If no pong arrives within the [`pong_timeout`](Self::pong_timeout), the connection is
+    /// considered dead: a client created with
+    /// [`connect_with_reconnects`](Self::connect_with_reconnects) reconnects, any other client
+    /// shuts down.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn ping_interval(self, interval: Duration) -> Self {
+        // if the server is gone there is no connection left to keep alive
+        let _ =
+            self.send(Instruction::Keepalive { ping_interval: Some(interval), pong_timeout: None });
+        self
+    }
+
+    /// Sets how long to wait for the pong after a ping, 10 seconds by default
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn pong_timeout(self, timeout: Duration) -> Self {
+        let _ =
+            self.send(Instruction::Keepalive { ping_interval: None, pong_timeout: Some(timeout) });
+        self
+    }
+
     fn send(&self, msg: Instruction) -> Result<(), ClientError> {
         self.instructions.unbounded_send(msg).map_err(to_client_error)
     }
@@ -170,12 +262,20 @@ impl JsonRpcClient for Ws {
     ) -> Result<R, ClientError> {
         let next_id = self.id.fetch_add(1, Ordering::SeqCst);
 
+        // keep the params of subscriptions to register them again after a reconnect
+        let subscription_params = if method == "eth_subscribe" {
+            Some(serde_json::value::to_raw_value(&params)?)
+        } else {
+            None
+        };
+
         // send the message
         let (sender, receiver) = oneshot::channel();
         let payload = Instruction::Request {
             id: next_id,
             request: serde_json::to_string(&Request::new(next_id, method, params))?,
             sender,
+            subscription_params,
         };
 
         // send the data
@@ -199,16 +299,91 @@ impl PubsubClient for Ws {
     }
 
     fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), ClientError> {
+        // the server also cancels the subscription on the node, under the id the node knows
         self.send(Instruction::Unsubscribe { id: id.into() })
     }
 }
 
+/// How the `WsServer` replaces a dropped connection
+struct Reconnect<S> {
+    connect: Box<dyn Fn() -> ConnectFuture<S> + Send + Sync>,
+    max_attempts: usize,
+}
+
+/// Pings the node regularly and expects a pong in time
+#[cfg(not(target_arch = "wasm32"))]
+struct Keepalive {
+    ping_interval: Duration,
+    pong_timeout: Duration,
+    next_ping: tokio::time::Instant,
+    /// When the pong for the last ping is due, `None` if there is no ping awaiting its pong
+    pong_deadline: Option<tokio::time::Instant>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Keepalive {
+    fn new(ping_interval: Duration, pong_timeout: Duration) -> Self {
+        let next_ping = tokio::time::Instant::now() + ping_interval;
+        Self { ping_interval, pong_timeout, next_ping, pong_deadline: None }
+    }
+
+    /// The next time the keepalive needs to act, i.e. send a ping or give up on the pong
+    fn deadline(&self) -> tokio::time::Instant {
+        self.pong_deadline.unwrap_or(self.next_ping)
+    }
+
+    fn ping_sent(&mut self) {
+        let now = tokio::time::Instant::now();
+        self.pong_deadline = Some(now + self.pong_timeout);
+        self.next_ping = now + self.ping_interval;
+    }
+
+    fn pong_received(&mut self) {
+        self.pong_deadline = None;
+    }
+}
+
+impl<S> Reconnect<S> {
+    fn new<F, Fut>(connect: F, max_attempts: usize) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<S, ClientError>> + Send + 'static,
+    {
+        Self {
+            connect: Box::new(move || -> ConnectFuture<S> { Box::pin(connect()) }),
+            max_attempts,
+        }
+    }
+}
+
 struct WsServer<S> {
     ws: Fuse<S>,
     instructions: Fuse<mpsc::UnboundedReceiver<Instruction>>,
 
     pending: BTreeMap<u64, Pending>,
+    /// Subscriptions by the id the `Ws` client knows them by
     subscriptions: BTreeMap<U256, Subscription>,
+    /// The request ids shared with the `Ws` client, for the requests sent by the server itself
+    ids: Arc<AtomicU64>,
+
+    /// Params of `eth_subscribe` requests that wait for their response
+    pending_subscriptions: BTreeMap<u64, Box<RawValue>>,
+    /// Params of the created subscriptions by client id, to register them again after a
+    /// reconnect
+    subscription_params: BTreeMap<U256, Box<RawValue>>,
+    /// `eth_subscribe` requests sent after a reconnect and the client id of the subscription
+    /// they register again
+    resubscriptions: BTreeMap<u64, U256>,
+    /// The id the node assigned on the current connection to each client id.
+    ///
+    /// Both are the same until a reconnect, or until an id of the new connection collides with
+    /// the client id of a subscription that was registered again.
+    node_ids: BTreeMap<U256, U256>,
+    /// The reverse of `node_ids`, to route notifications
+    client_ids: BTreeMap<U256, U256>,
+    reconnect: Option<Reconnect<S>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    keepalive: Keepalive,
 }
 
 impl<S> WsServer<S>
@@ -216,7 +391,7 @@ where
     S: Send + Sync + Stream<Item = WsStreamItem> + Sink<Message, Error = WsError> + Unpin,
 {
     /// Instantiates the Websocket Server
-    fn new(ws: S, requests: mpsc::UnboundedReceiver<Instruction>) -> Self {
+    fn new(ws: S, requests: mpsc::UnboundedReceiver<Instruction>, ids: Arc<AtomicU64>) -> Self {
         Self {
             // Fuse the 2 steams together, so that we can `select` them in the
             // Stream implementation
@@ -224,9 +399,25 @@ where
             instructions: requests.fuse(),
             pending: BTreeMap::default(),
             subscriptions: BTreeMap::default(),
+            ids,
+            pending_subscriptions: BTreeMap::default(),
+            subscription_params: BTreeMap::default(),
+            resubscriptions: BTreeMap::default(),
+            node_ids: BTreeMap::default(),
+            client_ids: BTreeMap::default(),
+            reconnect: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            keepalive: Keepalive::new(DEFAULT_PING_INTERVAL, DEFAULT_PONG_TIMEOUT),
         }
     }
 
+    /// Reconnects instead of shutting down if the connection drops
+    #[cfg_attr(target_arch = "wasm32", allow(unused))]
+    fn with_reconnect(mut self, reconnect: Reconnect<S>) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
     /// Returns whether the all work has been completed.
     ///
     /// If this method returns `true`, then the `instructions` channel has been closed and all
@@ -249,6 +440,12 @@ where
 
                 if let Err(e) = self.tick().await {
                     error!("Received a WebSocket error: {:?}", e);
+                    if self.reconnect.is_some() {
+                        match self.reconnect().await {
+                            Ok(()) => continue,
+                            Err(e) => error!("Failed to reconnect: {:?}", e),
+                        }
+                    }
                     self.close_all_subscriptions();
                     break
                 }
@@ -262,6 +459,57 @@ where
         tokio::spawn(f);
     }
 
+    /// Replaces the dropped connection and registers all active subscriptions again.
+    ///
+    /// Requests that are still waiting for a response are dropped.
+    async fn reconnect(&mut self) -> Result<(), ClientError> {
+        let reconnect = self.reconnect.as_ref().ok_or(ClientError::UnexpectedClose)?;
+
+        let mut attempt = 0;
+        let ws = loop {
+            attempt += 1;
+            match (reconnect.connect)().await {
+                Ok(ws) => break ws,
+                Err(err) if attempt < reconnect.max_attempts => {
+                    warn!("Reconnect attempt {} failed: {:?}", attempt, err);
+
+                    #[cfg(target_arch = "wasm32")]
+                    let _ = wasm_timer::Delay::new(RECONNECT_DELAY).await;
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        debug!("Reconnected after {} attempt(s)", attempt);
+
+        self.ws = ws.fuse();
+        self.pending.clear();
+        self.pending_subscriptions.clear();
+        self.resubscriptions.clear();
+        // the ids of the old connection mean nothing to the new one
+        self.node_ids.clear();
+        self.client_ids.clear();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.keepalive =
+                Keepalive::new(self.keepalive.ping_interval, self.keepalive.pong_timeout);
+        }
+
+        for (id, params) in &self.subscription_params {
+            if !self.subscriptions.contains_key(id) {
+                continue
+            }
+            let request_id = self.ids.fetch_add(1, Ordering::SeqCst);
+            let request =
+                serde_json::to_string(&Request::new(request_id, "eth_subscribe", params))?;
+            self.ws.send(Message::Text(request)).await?;
+            self.resubscriptions.insert(request_id, *id);
+        }
+        Ok(())
+    }
+
     // This will close all active subscriptions. Each process listening for
     // updates will observe the end of their subscription streams.
     fn close_all_subscriptions(&self) {
@@ -277,10 +525,14 @@ where
         id: u64,
         request: String,
         sender: Pending,
+        subscription_params: Option<Box<RawValue>>,
     ) -> Result<(), ClientError> {
         if self.pending.insert(id, sender).is_some() {
             warn!("Replacing a pending request with id {:?}", id);
         }
+        if let Some(params) = subscription_params {
+            self.pending_subscriptions.insert(id, params);
+        }
 
         if let Err(e) = self.ws.send(Message::Text(request)).await {
             error!("WS connection error: {:?}", e);
@@ -297,10 +549,35 @@ where
         Ok(())
     }
 
-    /// Dispatch a unsubscribe request
+    /// Dispatch a unsubscribe request and cancel the subscription on the node
     async fn service_unsubscribe(&mut self, id: U256) -> Result<(), ClientError> {
         if self.subscriptions.remove(&id).is_none() {
             warn!("Unsubscribing from non-existent subscription with id {:?}", id);
+            return Ok(())
+        }
+        let resubscribing = self.subscription_params.remove(&id).is_some();
+
+        let node_id = match self.node_ids.remove(&id) {
+            Some(node_id) => {
+                self.client_ids.remove(&node_id);
+                node_id
+            }
+            // the node has not answered the `eth_subscribe` sent after a reconnect yet, the
+            // subscription is cancelled once it does
+            None if resubscribing => return Ok(()),
+            // a subscription that was not created through this client
+            None => id,
+        };
+        self.send_unsubscribe(node_id).await
+    }
+
+    /// Cancels a subscription on the node without waiting for the response
+    async fn send_unsubscribe(&mut self, node_id: U256) -> Result<(), ClientError> {
+        let request_id = self.ids.fetch_add(1, Ordering::SeqCst);
+        let request =
+            serde_json::to_string(&Request::new(request_id, "eth_unsubscribe", [node_id]))?;
+        if let Err(e) = self.ws.send(Message::Text(request)).await {
+            error!("WS connection error: {:?}", e);
         }
         Ok(())
     }
@@ -308,12 +585,34 @@ where
     /// Dispatch an outgoing message
     async fn service(&mut self, instruction: Instruction) -> Result<(), ClientError> {
         match instruction {
-            Instruction::Request { id, request, sender } => {
-                self.service_request(id, request, sender).await
+            Instruction::Request { id, request, sender, subscription_params } => {
+                self.service_request(id, request, sender, subscription_params).await
             }
             Instruction::Subscribe { id, sink } => self.service_subscribe(id, sink).await,
             Instruction::Unsubscribe { id } => self.service_unsubscribe(id).await,
+            #[cfg(not(target_arch = "wasm32"))]
+            Instruction::Keepalive { ping_interval, pong_timeout } => {
+                let ping_interval = ping_interval.unwrap_or(self.keepalive.ping_interval);
+                let pong_timeout = pong_timeout.unwrap_or(self.keepalive.pong_timeout);
+                let pong_deadline = self.keepalive.pong_deadline;
+                self.keepalive =
+                    Keepalive { pong_deadline, ..Keepalive::new(ping_interval, pong_timeout) };
+                Ok(())
+            }
+            #[cfg(target_arch = "wasm32")]
+            Instruction::Keepalive { .. } => Ok(()),
+        }
+    }
+
+    /// Sends the next ping, or fails if the pong for the last one did not arrive in time
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn keepalive(&mut self) -> Result<(), ClientError> {
+        if self.keepalive.pong_deadline.is_some() {
+            return Err(ClientError::PongTimeout(self.keepalive.pong_timeout))
         }
+        self.ws.send(Message::Ping(Vec::new())).await?;
+        self.keepalive.ping_sent();
+        Ok(())
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -330,6 +629,21 @@ where
             Response::Notification { params, .. } => return self.handle_notification(params),
         };
 
+        if let Some(client_id) = self.resubscriptions.remove(&id) {
+            return self.handle_resubscription(client_id, result).await
+        }
+        let mut result = result;
+        if let Some(params) = self.pending_subscriptions.remove(&id) {
+            if let Some(Ok(node_id)) =
+                result.as_ref().ok().map(|res| serde_json::from_str::<U256>(res.get()))
+            {
+                let client_id = self.register_subscription(node_id, params);
+                if client_id != node_id {
+                    result = Ok(serde_json::value::to_raw_value(&client_id)?);
+                }
+            }
+        }
+
         if let Some(request) = self.pending.remove(&id) {
             if !request.is_canceled() {
                 request.send(result).map_err(to_client_error)?;
@@ -339,8 +653,56 @@ where
         Ok(())
     }
 
+    /// Assigns the client id of a subscription the node created with `node_id`.
+    ///
+    /// This is the node's id, unless a subscription that was registered again after a reconnect
+    /// already uses it as its client id.
+    fn register_subscription(&mut self, node_id: U256, params: Box<RawValue>) -> U256 {
+        let mut client_id = node_id;
+        while self.subscriptions.contains_key(&client_id) || self.node_ids.contains_key(&client_id)
+        {
+            client_id = client_id.overflowing_add(U256::one()).0;
+        }
+        self.node_ids.insert(client_id, node_id);
+        self.client_ids.insert(node_id, client_id);
+        self.subscription_params.insert(client_id, params);
+        client_id
+    }
+
+    /// Maps the id the node assigned to a subscription that was registered again after a
+    /// reconnect to its client id, or closes the subscription if that failed
+    async fn handle_resubscription(
+        &mut self,
+        client_id: U256,
+        result: Result<Box<RawValue>, JsonRpcError>,
+    ) -> Result<(), ClientError> {
+        match result.ok().and_then(|res| serde_json::from_str::<U256>(res.get()).ok()) {
+            // the stream was dropped while waiting for the node
+            Some(node_id) if !self.subscriptions.contains_key(&client_id) => {
+                self.send_unsubscribe(node_id).await?;
+            }
+            Some(node_id) => {
+                self.node_ids.insert(client_id, node_id);
+                self.client_ids.insert(node_id, client_id);
+            }
+            None => {
+                error!("Failed to subscribe again to subscription with id {:?}", client_id);
+                self.subscription_params.remove(&client_id);
+                if let Some(subscription) = self.subscriptions.remove(&client_id) {
+                    subscription.close_channel();
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn handle_notification(&mut self, params: Params<'_>) -> Result<(), ClientError> {
-        let id = params.subscription;
+        let id = match self.client_ids.get(&params.subscription) {
+            Some(id) => *id,
+            // the client id of another subscription, which the node knows by another id
+            None if self.node_ids.contains_key(&params.subscription) => return Ok(()),
+            None => params.subscription,
+        };
         if let Entry::Occupied(stream) = self.subscriptions.entry(id) {
             if let Err(err) = stream.get().unbounded_send(params.result.to_owned()) {
                 if err.is_disconnected() {
@@ -368,7 +730,11 @@ where
             Message::Text(inner) => self.handle_text(inner).await,
             Message::Frame(_) => Ok(()), // Server is allowed to send Raw frames
             Message::Ping(inner) => self.handle_ping(inner).await,
-            Message::Pong(_) => Ok(()), // Server is allowed to send unsolicited pongs.
+            // Server is allowed to send unsolicited pongs.
+            Message::Pong(_) => {
+                self.keepalive.pong_received();
+                Ok(())
+            }
             Message::Close(Some(frame)) => Err(ClientError::WsClosed(frame)),
             Message::Close(None) => Err(ClientError::UnexpectedClose),
             Message::Binary(buf) => Err(ClientError::UnexpectedBinary(buf)),
@@ -400,7 +766,12 @@ where
     #[allow(clippy::single_match)]
     #[cfg(not(target_arch = "wasm32"))]
     async fn tick(&mut self) -> Result<(), ClientError> {
+        let keepalive = tokio::time::sleep_until(self.keepalive.deadline()).fuse();
+        futures_util::pin_mut!(keepalive);
+
         futures_util::select! {
+            // Ping the node, or give up on the connection if it did not respond to the last ping
+            _ = keepalive => self.keepalive().await?,
             // Handle requests
             instruction = self.instructions.select_next_some() => {
                 self.service(instruction).await?;
@@ -466,6 +837,11 @@ pub enum ClientError {
     #[error("WebSocket connection closed unexpectedly")]
     UnexpectedClose,
 
+    /// Thrown if the node did not answer a ping in time, i.e. the connection is stale
+    #[error("no pong received within {0:?}")]
+    #[cfg(not(target_arch = "wasm32"))]
+    PongTimeout(Duration),
+
     /// Could not create an auth header for websocket handshake
     #[error(transparent)]
     #[cfg(not(target_arch = "wasm32"))]
@@ -484,7 +860,10 @@ pub enum ClientError {
 
 impl From<ClientError> for ProviderError {
     fn from(src: ClientError) -> Self {
-        ProviderError::JsonRpcClientError(Box::new(src))
+        match src {
+            ClientError::JsonRpcError(err) => ProviderError::JsonRpcError(err),
+            _ => ProviderError::JsonRpcClientError(Box::new(src)),
+        }
     }
 }
 
@@ -529,13 +908,359 @@ mod tests {
         assert_eq!(blocks, vec![1, 2, 3])
     }
 
+    #[tokio::test]
+    async fn subscription_skips_malformed_items_and_unsubscribes_on_drop() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, mut requests) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut ws = accept(stream).unwrap();
+            while let Ok(Message::Text(msg)) = ws.read_message() {
+                let req: Value = serde_json::from_str(&msg).unwrap();
+                let method = req["method"].as_str().unwrap().to_string();
+                let result = if method == "eth_subscribe" { json!("0x1") } else { json!(true) };
+                let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": result });
+                ws.write_message(Message::Text(resp.to_string())).unwrap();
+                if method == "eth_blockNumber" {
+                    // the subscription is registered by now, so notify it
+                    for result in [json!("not a number"), json!("0x2")] {
+                        let notification = json!({
+                            "jsonrpc": "2.0",
+                            "method": "eth_subscription",
+                            "params": { "subscription": "0x1", "result": result }
+                        });
+                        ws.write_message(Message::Text(notification.to_string())).unwrap();
+                    }
+                }
+                requests_tx.unbounded_send(req).unwrap();
+            }
+        });
+
+        let ws = Ws::connect(format!("ws://{addr}")).await.unwrap();
+        let provider = Provider::new(ws);
+        let mut stream = provider.subscribe::<_, U256>(["custom"]).await.unwrap();
+        assert_eq!(stream.id, U256::one());
+        provider.request::<_, bool>("eth_blockNumber", ()).await.unwrap();
+
+        // the malformed item is skipped instead of stalling the stream
+        assert_eq!(stream.next().await, Some(U256::from(2u64)));
+
+        drop(stream);
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_subscribe");
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_blockNumber");
+        let unsubscribe = requests.next().await.unwrap();
+        assert_eq!(unsubscribe["method"], "eth_unsubscribe");
+        assert_eq!(unsubscribe["params"], json!(["0x1"]));
+    }
+
+    #[tokio::test]
+    async fn unsubscribes_once() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, mut requests) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut ws = accept(stream).unwrap();
+            while let Ok(Message::Text(msg)) = ws.read_message() {
+                let req: Value = serde_json::from_str(&msg).unwrap();
+                let result =
+                    if req["method"] == "eth_subscribe" { json!("0x1") } else { json!(true) };
+                let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": result });
+                ws.write_message(Message::Text(resp.to_string())).unwrap();
+                requests_tx.unbounded_send(req).unwrap();
+            }
+        });
+
+        let provider = Provider::new(Ws::connect(format!("ws://{addr}")).await.unwrap());
+        let stream = provider.subscribe::<_, U256>(["newHeads"]).await.unwrap();
+        stream.unsubscribe().unwrap();
+        provider.request::<_, bool>("eth_blockNumber", ()).await.unwrap();
+
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_subscribe");
+        let unsubscribe = requests.next().await.unwrap();
+        assert_eq!(unsubscribe["method"], "eth_unsubscribe");
+        assert_eq!(unsubscribe["params"], json!(["0x1"]));
+        // dropping the stream did not cancel it again
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_blockNumber");
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_resubscribes() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, mut requests) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            // the node assigns another id to the subscription on the second connection
+            for sub_id in ["0x1", "0x2"] {
+                let (stream, _) = listener.accept().unwrap();
+                let mut ws = accept(stream).unwrap();
+                while let Ok(Message::Text(msg)) = ws.read_message() {
+                    let req: Value = serde_json::from_str(&msg).unwrap();
+                    let method = req["method"].as_str().unwrap().to_string();
+                    let result =
+                        if method == "eth_subscribe" { json!(sub_id) } else { json!(true) };
+                    let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": result });
+                    ws.write_message(Message::Text(resp.to_string())).unwrap();
+                    requests_tx.unbounded_send(req).unwrap();
+
+                    // notify once the subscription is registered, then drop the first connection
+                    let notify = matches!(
+                        (sub_id, method.as_str()),
+                        ("0x1", "eth_blockNumber") | ("0x2", "eth_subscribe")
+                    );
+                    if notify {
+                        let notification = json!({
+                            "jsonrpc": "2.0",
+                            "method": "eth_subscription",
+                            "params": { "subscription": sub_id, "result": sub_id }
+                        });
+                        ws.write_message(Message::Text(notification.to_string())).unwrap();
+                        if sub_id == "0x1" {
+                            break
+                        }
+                    }
+                }
+            }
+        });
+
+        let ws = Ws::connect_with_reconnects(format!("ws://{addr}"), 3).await.unwrap();
+        let provider = Provider::new(ws);
+        let mut stream = provider.subscribe::<_, U256>(["newHeads"]).await.unwrap();
+        provider.request::<_, bool>("eth_blockNumber", ()).await.unwrap();
+
+        // the stream keeps its id and receives the notifications of both connections
+        assert_eq!(stream.next().await, Some(U256::from(1u64)));
+        assert_eq!(stream.next().await, Some(U256::from(2u64)));
+        assert_eq!(stream.id, U256::one());
+
+        drop(stream);
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_subscribe");
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_blockNumber");
+        let resubscribe = requests.next().await.unwrap();
+        assert_eq!(resubscribe["method"], "eth_subscribe");
+        assert_eq!(resubscribe["params"], json!(["newHeads"]));
+        // the subscription is cancelled under the id assigned by the new connection
+        let unsubscribe = requests.next().await.unwrap();
+        assert_eq!(unsubscribe["method"], "eth_unsubscribe");
+        assert_eq!(unsubscribe["params"], json!(["0x2"]));
+        // and not under its stale original id
+        provider.request::<_, bool>("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_blockNumber");
+    }
+
+    #[tokio::test]
+    async fn keeps_ids_unique_after_node_restart() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, mut requests) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            // the node numbers subscriptions with a counter, which starts over after a restart
+            for restarted in [false, true] {
+                let (stream, _) = listener.accept().unwrap();
+                let mut ws = accept(stream).unwrap();
+                let mut next_sub_id = 1u64;
+                while let Ok(Message::Text(msg)) = ws.read_message() {
+                    let req: Value = serde_json::from_str(&msg).unwrap();
+                    let method = req["method"].as_str().unwrap().to_string();
+                    requests_tx.unbounded_send(req.clone()).unwrap();
+                    match method.as_str() {
+                        "eth_subscribe" => {
+                            let sub_id = format!("{next_sub_id:#x}");
+                            next_sub_id += 1;
+                            let resp =
+                                json!({ "jsonrpc": "2.0", "id": req["id"], "result": sub_id });
+                            ws.write_message(Message::Text(resp.to_string())).unwrap();
+                        }
+                        // the restart
+                        "eth_unsubscribe" if !restarted => break,
+                        "eth_unsubscribe" => {}
+                        _ => {
+                            let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": true });
+                            ws.write_message(Message::Text(resp.to_string())).unwrap();
+                            for sub_id in ["0x1", "0x2"] {
+                                let notification = json!({
+                                    "jsonrpc": "2.0",
+                                    "method": "eth_subscription",
+                                    "params": { "subscription": sub_id, "result": sub_id }
+                                });
+                                ws.write_message(Message::Text(notification.to_string())).unwrap();
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        let ws = Ws::connect_with_reconnects(format!("ws://{addr}"), 3).await.unwrap();
+        let provider = Provider::new(ws);
+        let first = provider.subscribe::<_, U256>(["newHeads"]).await.unwrap();
+        let mut second = provider.subscribe::<_, U256>(["logs"]).await.unwrap();
+        assert_eq!(second.id, U256::from(2u64));
+        drop(first);
+        for method in ["eth_subscribe", "eth_subscribe", "eth_unsubscribe"] {
+            assert_eq!(requests.next().await.unwrap()["method"], method);
+        }
+
+        // after the restart the node knows the second subscription as 0x1 and assigns 0x2 to the
+        // next one, which must not replace the second subscription
+        let resubscribe = requests.next().await.unwrap();
+        assert_eq!(resubscribe["params"], json!(["logs"]));
+        let mut third = provider.subscribe::<_, U256>(["newPendingTransactions"]).await.unwrap();
+        assert_ne!(third.id, second.id);
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_subscribe");
+
+        provider.request::<_, bool>("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_blockNumber");
+        assert_eq!(second.next().await, Some(U256::from(1u64)));
+        assert_eq!(third.next().await, Some(U256::from(2u64)));
+
+        // both are cancelled under the ids of the new connection
+        drop(second);
+        drop(third);
+        for sub_id in ["0x1", "0x2"] {
+            let unsubscribe = requests.next().await.unwrap();
+            assert_eq!(unsubscribe["method"], "eth_unsubscribe");
+            assert_eq!(unsubscribe["params"], json!([sub_id]));
+        }
+    }
+
+    #[tokio::test]
+    async fn subscriptions_end_when_reconnecting_fails() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut ws = accept(stream).unwrap();
+            // answer the subscription, then shut down the "node" for good
+            let msg = ws.read_message().unwrap().into_text().unwrap();
+            let req: Value = serde_json::from_str(&msg).unwrap();
+            let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": "0x1" });
+            ws.write_message(Message::Text(resp.to_string())).unwrap();
+        });
+
+        let ws = Ws::connect_with_reconnects(format!("ws://{addr}"), 2).await.unwrap();
+        let provider = Provider::new(ws);
+        let mut stream = provider.subscribe::<_, U256>(["newHeads"]).await.unwrap();
+        assert_eq!(stream.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn reconnects_if_pongs_are_missing() {
+        use crate::{Middleware, Provider};
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, mut requests) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            let mut stale = Vec::new();
+            for sub_id in ["0x1", "0x2"] {
+                let (stream, _) = listener.accept().unwrap();
+                let mut ws = accept(stream).unwrap();
+                let msg = ws.read_message().unwrap().into_text().unwrap();
+                let req: Value = serde_json::from_str(&msg).unwrap();
+                let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": sub_id });
+                ws.write_message(Message::Text(resp.to_string())).unwrap();
+                requests_tx.unbounded_send(req).unwrap();
+                // pongs are only sent while reading, so the connection stays open but stale
+                stale.push(ws);
+            }
+        });
+
+        let provider =
+            Provider::new(Ws::connect_with_reconnects(format!("ws://{addr}"), 1).await.unwrap())
+                .ping_interval(Duration::from_millis(100))
+                .pong_timeout(Duration::from_millis(100));
+        let _stream = provider.subscribe::<_, U256>(["newHeads"]).await.unwrap();
+        assert_eq!(requests.next().await.unwrap()["method"], "eth_subscribe");
+
+        // the missing pong triggers a reconnect, which registers the subscription again
+        let resubscribe = tokio::time::timeout(Duration::from_secs(5), requests.next())
+            .await
+            .expect("no reconnect after the pong timeout")
+            .unwrap();
+        assert_eq!(resubscribe["method"], "eth_subscribe");
+        assert_eq!(resubscribe["params"], json!(["newHeads"]));
+    }
+
+    #[tokio::test]
+    async fn subscribes_to_typed_new_heads() {
+        use crate::{Middleware, Provider};
+        use ethers_core::types::H256;
+        use serde_json::{json, Value};
+        use tokio_tungstenite::tungstenite::accept;
+
+        let hash = H256::repeat_byte(0x11);
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut ws = accept(stream).unwrap();
+            let msg = ws.read_message().unwrap().into_text().unwrap();
+            let req: Value = serde_json::from_str(&msg).unwrap();
+            assert_eq!(req["params"], json!(["newHeads"]));
+            let resp = json!({ "jsonrpc": "2.0", "id": req["id"], "result": "0x1" });
+            ws.write_message(Message::Text(resp.to_string())).unwrap();
+
+            // a header without size, transactions and uncles
+            let header = json!({
+                "number": "0x2a",
+                "hash": hash,
+                "parentHash": H256::zero(),
+                "gasLimit": "0x1c9c380",
+                "gasUsed": "0x0",
+                "timestamp": "0x6389c0d3",
+                "baseFeePerGas": "0x7"
+            });
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "eth_subscription",
+                "params": { "subscription": "0x1", "result": header }
+            });
+            ws.write_message(Message::Text(notification.to_string())).unwrap();
+            // keep the connection open until the client is done
+            let _ = ws.read_message();
+        });
+
+        let provider = Provider::new(Ws::connect(format!("ws://{addr}")).await.unwrap());
+        let mut stream = provider.subscribe_blocks().await.unwrap();
+        let block = stream.next().await.unwrap();
+        assert_eq!(block.number, Some(42u64.into()));
+        assert_eq!(block.hash, Some(hash));
+        assert!(block.is_header_only());
+    }
+
     #[tokio::test]
     async fn deserialization_fails() {
         let anvil = Anvil::new().block_time(1u64).spawn();
         let (ws, _) = tokio_tungstenite::connect_async(anvil.ws_endpoint()).await.unwrap();
         let malformed_data = String::from("not a valid message");
         let (_, stream) = mpsc::unbounded();
-        let resp = WsServer::new(ws, stream).handle_text(malformed_data).await;
+        let resp = WsServer::new(ws, stream, Arc::new(AtomicU64::new(1)))
+            .handle_text(malformed_data)
+            .await;
         resp.unwrap_err();
     }
 }
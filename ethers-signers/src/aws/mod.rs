@@ -15,7 +15,7 @@ use rusoto_kms::{
 use tracing::{debug, instrument, trace};
 
 mod utils;
-use utils::{apply_eip155, rsig_to_ethsig, verifying_key_to_address};
+use crate::kms::{apply_eip155, rsig_to_ethsig, verifying_key_to_address};
 
 /// An ethers Signer that uses keys held in Amazon AWS KMS.
 ///
@@ -26,22 +26,22 @@ use utils::{apply_eip155, rsig_to_ethsig, verifying_key_to_address};
 /// signer. This means that the new function is `async` and must be called
 /// within some runtime.
 ///
-/// ```compile_fail
-/// use rusoto_core::Client;
-/// use rusoto_kms::{Kms, KmsClient};
+/// ```no_run
+/// use ethers_core::types::H256;
+/// use ethers_signers::{AwsSigner, Signer};
+/// use rusoto_core::{credential::EnvironmentProvider, Client, HttpClient, Region};
+/// use rusoto_kms::KmsClient;
 ///
-/// user ethers_signers::Signer;
-///
-/// let client = Client::new_with(
-///     EnvironmentProvider::default(),
-///     HttpClient::new().unwrap()
-/// );
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new_with(EnvironmentProvider::default(), HttpClient::new()?);
 /// let kms_client = KmsClient::new_with_client(client, Region::UsWest1);
 /// let key_id = "...";
 /// let chain_id = 1;
 ///
 /// let signer = AwsSigner::new(kms_client, key_id, chain_id).await?;
 /// let sig = signer.sign_message(H256::zero()).await?;
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Clone)]
 pub struct AwsSigner {
@@ -0,0 +1,416 @@
+//! Google Cloud KMS-based Signer
+
+use ethers_core::{
+    k256::ecdsa::{Error as K256Error, Signature as KSig, VerifyingKey},
+    types::{
+        transaction::{eip2718::TypedTransaction, eip712::Eip712},
+        Address, Signature as EthSig, H256,
+    },
+    utils::hash_message,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, instrument, trace};
+
+mod utils;
+use crate::kms::{apply_eip155, verifying_key_to_address};
+
+/// The Cloud KMS REST API endpoint
+const CLOUD_KMS_URL: &str = "https://cloudkms.googleapis.com/v1";
+
+/// An ethers Signer that uses keys held in Google Cloud KMS.
+///
+/// The GCP Signer passes signing requests to the Cloud KMS REST API. Keys must be
+/// `EC_SIGN_SECP256K1_SHA256` asymmetric signing keys, identified by their project, location, key
+/// ring, key name and key version. Requests are authorized with the OAuth 2.0 access tokens of a
+/// [`GcpTokenProvider`], e.g. a fixed token like the output of `gcloud auth print-access-token`.
+///
+/// Because the public key is unknown, we retrieve it on instantiation of the
+/// signer. This means that the new function is `async` and must be called
+/// within some runtime.
+///
+/// ```no_run
+/// use ethers_core::types::H256;
+/// use ethers_signers::{GcpKmsSigner, Signer};
+///
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let access_token = std::env::var("GCP_ACCESS_TOKEN")?;
+/// let chain_id = 1;
+///
+/// let signer = GcpKmsSigner::new(
+///     "my-project",
+///     "global",
+///     "my-keyring",
+///     "my-key",
+///     1,
+///     access_token,
+///     chain_id,
+/// )
+/// .await?;
+/// let sig = signer.sign_message(H256::zero()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GcpKmsSigner {
+    client: reqwest::Client,
+    token_provider: Arc<dyn GcpTokenProvider>,
+    key_name: String,
+    chain_id: u64,
+    pubkey: VerifyingKey,
+    address: Address,
+}
+
+impl std::fmt::Debug for GcpKmsSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpKmsSigner")
+            .field("key_name", &self.key_name)
+            .field("chain_id", &self.chain_id)
+            .field("pubkey", &hex::encode(self.pubkey.to_bytes()))
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for GcpKmsSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GcpKmsSigner {{ address: {}, chain_id: {}, key_name: {} }}",
+            self.address, self.chain_id, self.key_name
+        )
+    }
+}
+
+/// Errors produced by the GcpKmsSigner
+#[derive(thiserror::Error, Debug)]
+pub enum GcpKmsSignerError {
+    /// Error when sending a request to Cloud KMS or reading its response
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    /// Thrown when the [`GcpTokenProvider`] fails to provide an access token
+    #[error("failed to get an access token: {0}")]
+    AccessToken(Box<dyn std::error::Error + Send + Sync>),
+    /// Error returned by the Cloud KMS API
+    #[error("cloud KMS responded with {status}: {message}")]
+    Api { status: u16, message: String },
+    #[error("{0}")]
+    K256(#[from] K256Error),
+    #[error("{0}")]
+    Spki(spki::Error),
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
+    /// Thrown when the key's algorithm is not `EC_SIGN_SECP256K1_SHA256`
+    #[error("unsupported key algorithm {0}, expected EC_SIGN_SECP256K1_SHA256")]
+    UnsupportedAlgorithm(String),
+    /// Thrown when the signature does not recover to the key's public key
+    #[error("the signature does not match the key's public key")]
+    SignatureMismatch,
+    #[error("{0}")]
+    Other(String),
+    /// Error type from Eip712Error message
+    #[error("error encoding eip712 struct: {0:?}")]
+    Eip712Error(String),
+}
+
+/// Provides the OAuth 2.0 access tokens that authorize the requests of a [`GcpKmsSigner`].
+///
+/// Access tokens expire after about an hour, so long-running signers should refresh them here.
+/// The provider is asked for a token before every request, a fixed token is a `String`.
+///
+/// ```no_run
+/// use ethers_signers::GcpTokenProvider;
+/// use std::{error::Error, sync::RwLock};
+///
+/// /// Fetches a new token once the cached one expired
+/// #[derive(Debug)]
+/// struct RefreshingToken {
+///     cached: RwLock<Option<(String, std::time::Instant)>>,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl GcpTokenProvider for RefreshingToken {
+///     async fn access_token(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+///         if let Some((token, expiry)) = self.cached.read().unwrap().clone() {
+///             if expiry > std::time::Instant::now() {
+///                 return Ok(token)
+///             }
+///         }
+///         // request a new token from the metadata server or with a service account here
+///         # unimplemented!()
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait GcpTokenProvider: std::fmt::Debug + Send + Sync {
+    /// Returns a valid access token
+    async fn access_token(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+#[async_trait::async_trait]
+impl GcpTokenProvider for String {
+    async fn access_token(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.clone())
+    }
+}
+
+impl From<String> for GcpKmsSignerError {
+    fn from(s: String) -> Self {
+        Self::Other(s)
+    }
+}
+
+impl From<spki::Error> for GcpKmsSignerError {
+    fn from(e: spki::Error) -> Self {
+        Self::Spki(e)
+    }
+}
+
+/// Response of `cryptoKeyVersions.getPublicKey`
+#[derive(Debug, Deserialize)]
+struct PublicKeyResponse {
+    pem: String,
+    algorithm: String,
+}
+
+/// Request of `cryptoKeyVersions.asymmetricSign`
+#[derive(Debug, Serialize)]
+struct SignRequest {
+    digest: Digest,
+}
+
+#[derive(Debug, Serialize)]
+struct Digest {
+    sha256: String,
+}
+
+/// Response of `cryptoKeyVersions.asymmetricSign`
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+/// Error body returned by the Cloud KMS API
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetails {
+    message: String,
+}
+
+/// Returns the response body, or a `GcpKmsSignerError::Api` if the request failed
+async fn check_response(resp: reqwest::Response) -> Result<String, GcpKmsSignerError> {
+    let status = resp.status();
+    let body = resp.text().await?;
+    trace!("{}", body);
+    if status.is_success() {
+        return Ok(body)
+    }
+    let message =
+        serde_json::from_str::<ErrorResponse>(&body).map(|err| err.error.message).unwrap_or(body);
+    Err(GcpKmsSignerError::Api { status: status.as_u16(), message })
+}
+
+/// Gets an access token from `token_provider`
+async fn access_token(token_provider: &dyn GcpTokenProvider) -> Result<String, GcpKmsSignerError> {
+    token_provider.access_token().await.map_err(GcpKmsSignerError::AccessToken)
+}
+
+#[instrument(err, skip(client, token_provider))]
+async fn request_get_pubkey(
+    client: &reqwest::Client,
+    token_provider: &dyn GcpTokenProvider,
+    key_name: &str,
+) -> Result<PublicKeyResponse, GcpKmsSignerError> {
+    debug!("Dispatching get_public_key");
+
+    let resp = client
+        .get(format!("{CLOUD_KMS_URL}/{key_name}/publicKey"))
+        .bearer_auth(access_token(token_provider).await?)
+        .send()
+        .await?;
+    let body = check_response(resp).await?;
+    serde_json::from_str(&body).map_err(|err| GcpKmsSignerError::Other(err.to_string()))
+}
+
+#[instrument(err, skip(client, token_provider, digest), fields(digest = %hex::encode(digest)))]
+async fn request_sign_digest(
+    client: &reqwest::Client,
+    token_provider: &dyn GcpTokenProvider,
+    key_name: &str,
+    digest: [u8; 32],
+) -> Result<SignResponse, GcpKmsSignerError> {
+    debug!("Dispatching asymmetric_sign");
+
+    let req = SignRequest { digest: Digest { sha256: base64::encode(digest) } };
+    trace!("{:?}", &req);
+    let resp = client
+        .post(format!("{CLOUD_KMS_URL}/{key_name}:asymmetricSign"))
+        .bearer_auth(access_token(token_provider).await?)
+        .json(&req)
+        .send()
+        .await?;
+    let body = check_response(resp).await?;
+    serde_json::from_str(&body).map_err(|err| GcpKmsSignerError::Other(err.to_string()))
+}
+
+impl GcpKmsSigner {
+    /// Instantiate a new signer for the given key version.
+    ///
+    /// This function retrieves the public key from Cloud KMS and calculates the
+    /// Ethereum address. It is therefore `async`.
+    pub async fn new(
+        project: &str,
+        location: &str,
+        keyring: &str,
+        key: &str,
+        version: u32,
+        token_provider: impl GcpTokenProvider + 'static,
+        chain_id: u64,
+    ) -> Result<GcpKmsSigner, GcpKmsSignerError> {
+        let key_name = format!(
+            "projects/{project}/locations/{location}/keyRings/{keyring}/cryptoKeys/{key}/cryptoKeyVersions/{version}"
+        );
+        Self::with_client(reqwest::Client::new(), key_name, token_provider, chain_id).await
+    }
+
+    /// Instantiate a new signer from an existing `reqwest::Client` and the full resource name of
+    /// the key version, i.e.
+    /// `projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*`
+    #[instrument(err, skip(client, token_provider, chain_id))]
+    pub async fn with_client(
+        client: reqwest::Client,
+        key_name: String,
+        token_provider: impl GcpTokenProvider + 'static,
+        chain_id: u64,
+    ) -> Result<GcpKmsSigner, GcpKmsSignerError> {
+        let token_provider = Arc::new(token_provider);
+        let pubkey = request_get_pubkey(&client, token_provider.as_ref(), &key_name)
+            .await
+            .and_then(utils::decode_pubkey)?;
+        let address = verifying_key_to_address(&pubkey);
+
+        debug!(
+            "Instantiated GCP signer with pubkey 0x{} and address 0x{}",
+            hex::encode(pubkey.to_bytes()),
+            hex::encode(address)
+        );
+
+        Ok(Self { client, token_provider, key_name, chain_id, pubkey, address })
+    }
+
+    /// Returns the full resource name of the key version used by this signer
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    /// Returns the public key of this signer's key version
+    pub fn pubkey(&self) -> &VerifyingKey {
+        &self.pubkey
+    }
+
+    /// Sign a digest with this signer's key
+    pub async fn sign_digest(&self, digest: [u8; 32]) -> Result<KSig, GcpKmsSignerError> {
+        request_sign_digest(&self.client, self.token_provider.as_ref(), &self.key_name, digest)
+            .await
+            .and_then(utils::decode_signature)
+    }
+
+    /// Sign a digest with this signer's key and recover the `v` value by checking which
+    /// recovery id yields the signer's public key
+    async fn sign_digest_recoverable(&self, digest: [u8; 32]) -> Result<EthSig, GcpKmsSignerError> {
+        let sig = self.sign_digest(digest).await?;
+        utils::sig_to_ethsig(&sig, digest, &self.pubkey)
+    }
+
+    /// Sign a digest with this signer's key and add the eip155 `v` value
+    /// corresponding to the input chain_id
+    #[instrument(err, skip(digest), fields(digest = %hex::encode(digest)))]
+    async fn sign_digest_with_eip155(
+        &self,
+        digest: H256,
+        chain_id: u64,
+    ) -> Result<EthSig, GcpKmsSignerError> {
+        let mut sig = self.sign_digest_recoverable(digest.into()).await?;
+        apply_eip155(&mut sig, chain_id);
+        Ok(sig)
+    }
+}
+
+#[async_trait::async_trait]
+impl super::Signer for GcpKmsSigner {
+    type Error = GcpKmsSignerError;
+
+    #[instrument(err, skip(message))]
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<EthSig, Self::Error> {
+        let message_hash = hash_message(message.as_ref());
+        trace!("{:?}", message_hash);
+
+        self.sign_digest_with_eip155(message_hash, self.chain_id).await
+    }
+
+    #[instrument(err)]
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<EthSig, Self::Error> {
+        let mut tx_with_chain = tx.clone();
+        let chain_id = tx_with_chain.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+        tx_with_chain.set_chain_id(chain_id);
+
+        let sighash = tx_with_chain.sighash();
+        self.sign_digest_with_eip155(sighash, chain_id).await
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<EthSig, Self::Error> {
+        let digest =
+            payload.encode_eip712().map_err(|e| Self::Error::Eip712Error(e.to_string()))?;
+
+        self.sign_digest_recoverable(digest).await
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the signer's chain id
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Sets the signer's chain id
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Expired;
+
+    #[async_trait::async_trait]
+    impl GcpTokenProvider for Expired {
+        async fn access_token(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+            Err("refresh token revoked".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn asks_the_token_provider() {
+        assert_eq!(access_token(&"token".to_string()).await.unwrap(), "token");
+
+        let err = access_token(&Expired).await.unwrap_err();
+        assert!(matches!(err, GcpKmsSignerError::AccessToken(_)));
+        assert_eq!(err.to_string(), "failed to get an access token: refresh token revoked");
+    }
+}
@@ -0,0 +1,124 @@
+//! These utils are NOT meant for general usage. They are ONLY meant for use
+//! within this module.
+
+use std::convert::TryFrom;
+
+use ethers_core::{
+    k256::ecdsa::{
+        recoverable::{Id, Signature as RSig},
+        Signature as KSig, VerifyingKey,
+    },
+    types::Signature as EthSig,
+};
+
+use super::{GcpKmsSignerError, PublicKeyResponse, SignResponse};
+use crate::kms::{check_candidate, rsig_to_ethsig};
+
+/// The only Cloud KMS key algorithm usable for Ethereum signatures
+const SECP256K1_ALGORITHM: &str = "EC_SIGN_SECP256K1_SHA256";
+
+/// Converts a signature under a known key to an ethers signature, recovering the `v` value by
+/// trial/error
+pub(super) fn sig_to_ethsig(
+    sig: &KSig,
+    digest: [u8; 32],
+    vk: &VerifyingKey,
+) -> Result<EthSig, GcpKmsSignerError> {
+    for id in 0..2 {
+        let candidate = RSig::new(sig, Id::new(id)?)?;
+        if check_candidate(&candidate, digest, vk) {
+            return Ok(rsig_to_ethsig(&candidate))
+        }
+    }
+    Err(GcpKmsSignerError::SignatureMismatch)
+}
+
+/// Decodes the DER bytes of a PEM encoded public key
+fn pem_to_der(pem: &str) -> Result<Vec<u8>, GcpKmsSignerError> {
+    let body: String = pem.lines().filter(|line| !line.starts_with("-----")).collect();
+    Ok(base64::decode(body.trim())?)
+}
+
+/// Decode a Cloud KMS Pubkey response
+pub(super) fn decode_pubkey(resp: PublicKeyResponse) -> Result<VerifyingKey, GcpKmsSignerError> {
+    if resp.algorithm != SECP256K1_ALGORITHM {
+        return Err(GcpKmsSignerError::UnsupportedAlgorithm(resp.algorithm))
+    }
+
+    let raw = pem_to_der(&resp.pem)?;
+    let spk = spki::SubjectPublicKeyInfo::try_from(raw.as_ref())?;
+    let key = VerifyingKey::from_sec1_bytes(spk.subject_public_key)?;
+
+    Ok(key)
+}
+
+/// Decode a Cloud KMS Signature response
+pub(super) fn decode_signature(resp: SignResponse) -> Result<KSig, GcpKmsSignerError> {
+    let raw = base64::decode(resp.signature)?;
+
+    let sig = KSig::from_der(&raw)?;
+    Ok(sig.normalize_s().unwrap_or(sig))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kms::verifying_key_to_address;
+    use ethers_core::k256::{
+        ecdsa::{signature::DigestSigner, SigningKey},
+        elliptic_curve::sec1::ToEncodedPoint,
+    };
+    use sha2::{Digest, Sha256};
+
+    // secp256k1 public key in the format returned by `cryptoKeyVersions.getPublicKey`
+    const PEM: &str = "-----BEGIN PUBLIC KEY-----
+MFYwEAYHKoZIzj0CAQYFK4EEAAoDQgAE3aD7r5bGTyl4z7l9XzsBDjtXbAREZe2J
+60dXnsrBgWHK+L24fLBszcCS8gYT3nPegRTQQt2WbUfUItANFmfDKw==
+-----END PUBLIC KEY-----
+";
+
+    #[test]
+    fn decodes_pem_pubkey() {
+        let key = decode_pubkey(PublicKeyResponse {
+            pem: PEM.to_string(),
+            algorithm: SECP256K1_ALGORITHM.to_string(),
+        })
+        .unwrap();
+        let encoded = key.to_encoded_point(false);
+        assert_eq!(
+            hex::encode(&encoded.as_bytes()[1..33]),
+            "dda0fbaf96c64f2978cfb97d5f3b010e3b576c044465ed89eb47579ecac18161"
+        );
+    }
+
+    #[test]
+    fn rejects_other_algorithms() {
+        let err = decode_pubkey(PublicKeyResponse {
+            pem: PEM.to_string(),
+            algorithm: "EC_SIGN_P256_SHA256".to_string(),
+        })
+        .unwrap_err();
+        assert!(matches!(err, GcpKmsSignerError::UnsupportedAlgorithm(_)));
+    }
+
+    #[test]
+    fn recovers_v_of_der_signature() {
+        let key = SigningKey::from_bytes(&[1u8; 32]).unwrap();
+        let digest = Sha256::new_with_prefix(b"hello world");
+        let digest_bytes: [u8; 32] = digest.clone().finalize().into();
+        let sig: KSig = key.sign_digest(digest);
+
+        let resp = SignResponse { signature: base64::encode(sig.to_der()) };
+        let sig = decode_signature(resp).unwrap();
+        let eth_sig = sig_to_ethsig(&sig, digest_bytes, &key.verifying_key()).unwrap();
+
+        let address = verifying_key_to_address(&key.verifying_key());
+        eth_sig.verify(ethers_core::types::H256::from(digest_bytes), address).unwrap();
+
+        let other = SigningKey::from_bytes(&[2u8; 32]).unwrap();
+        assert!(matches!(
+            sig_to_ethsig(&sig, digest_bytes, &other.verifying_key()),
+            Err(GcpKmsSignerError::SignatureMismatch)
+        ));
+    }
+}
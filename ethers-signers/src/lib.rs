@@ -34,12 +34,21 @@ pub use trezor::{
 #[cfg(feature = "yubi")]
 pub use yubihsm;
 
+#[cfg(any(feature = "aws", feature = "gcp"))]
+mod kms;
+
 #[cfg(feature = "aws")]
 mod aws;
 
 #[cfg(feature = "aws")]
 pub use aws::{AwsSigner, AwsSignerError};
 
+#[cfg(feature = "gcp")]
+mod gcp;
+
+#[cfg(feature = "gcp")]
+pub use gcp::{GcpKmsSigner, GcpKmsSignerError, GcpTokenProvider};
+
 use async_trait::async_trait;
 use ethers_core::types::{
     transaction::{eip2718::TypedTransaction, eip712::Eip712},
@@ -76,6 +85,11 @@ pub trait Signer: std::fmt::Debug + Send + Sync {
     ) -> Result<Signature, Self::Error>;
 
     /// Returns the signer's Ethereum Address
+    ///
+    /// This is called synchronously, e.g. when wrapping the signer in a `SignerMiddleware`, so
+    /// signers whose address requires a network round trip (HSMs, cloud KMS, hardware wallets)
+    /// should resolve it once in their async constructor and cache it, see
+    /// `AwsSigner::new` or `Ledger::new`.
     fn address(&self) -> Address;
 
     /// Returns the signer's chain id
@@ -18,9 +18,9 @@ use ethers_core::{
     },
     types::{
         transaction::{eip2718::TypedTransaction, eip712::Eip712},
-        Address, Signature, H256, U256,
+        Address, Signature, TransactionRequest, H256, U256,
     },
-    utils::hash_message,
+    utils::{hash_message, keccak256},
 };
 use hash::Sha256Proxy;
 
@@ -148,6 +148,18 @@ impl<D: DigestSigner<Sha256Proxy, RecoverableSignature>> Wallet<D> {
         sig
     }
 
+    /// Signs a legacy transaction without [EIP-155](https://eips.ethereum.org/EIPS/eip-155)
+    /// replay protection, i.e. the sighash does not commit to a chain id and `v` is `27` or `28`.
+    ///
+    /// Such a transaction can be replayed on every chain, this only exists for compatibility
+    /// testing.
+    #[deprecated(note = "the signed transaction can be replayed on other chains, use \
+                         `sign_transaction_sync` instead")]
+    pub fn legacy_sign(&self, tx: &TransactionRequest) -> Signature {
+        let sighash = keccak256(tx.rlp_unsigned().as_ref()).into();
+        self.sign_hash(sighash)
+    }
+
     /// Signs the provided hash.
     pub fn sign_hash(&self, hash: H256) -> Signature {
         let recoverable_sig: RecoverableSignature =
@@ -169,6 +181,13 @@ impl<D: DigestSigner<Sha256Proxy, RecoverableSignature>> Wallet<D> {
     }
 }
 
+// The address and chain id are public, the signer clears its key material itself
+#[cfg(feature = "zeroize")]
+impl<D> zeroize::ZeroizeOnDrop for Wallet<D> where
+    D: DigestSigner<Sha256Proxy, RecoverableSignature> + zeroize::ZeroizeOnDrop
+{
+}
+
 // do not log the signer
 impl<D: DigestSigner<Sha256Proxy, RecoverableSignature>> fmt::Debug for Wallet<D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
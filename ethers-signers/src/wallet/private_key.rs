@@ -18,6 +18,12 @@ use std::path::Path;
 use std::str::FromStr;
 use thiserror::Error;
 
+/// Buffer holding raw private key bytes, cleared on drop if the `zeroize` feature is enabled
+#[cfg(feature = "zeroize")]
+type SecretBytes = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type SecretBytes = Vec<u8>;
+
 #[derive(Error, Debug)]
 /// Error thrown by the Wallet module
 pub enum WalletError {
@@ -66,6 +72,7 @@ impl Wallet<SigningKey> {
         S: AsRef<[u8]>,
     {
         let (secret, uuid) = eth_keystore::new(dir, rng, password, name)?;
+        let secret = SecretBytes::from(secret);
         let signer = SigningKey::from_bytes(secret.as_slice())?;
         let address = secret_key_to_address(&signer);
         Ok((Self { signer, address, chain_id: 1 }, uuid))
@@ -78,13 +85,21 @@ impl Wallet<SigningKey> {
         P: AsRef<Path>,
         S: AsRef<[u8]>,
     {
-        let secret = eth_keystore::decrypt_key(keypath, password)?;
+        let secret = SecretBytes::from(eth_keystore::decrypt_key(keypath, password)?);
         let signer = SigningKey::from_bytes(secret.as_slice())?;
         let address = secret_key_to_address(&signer);
         Ok(Self { signer, address, chain_id: 1 })
     }
 
+    /// Creates a new random keypair using the operating system's random number generator
+    pub fn new_random() -> Self {
+        Self::new(&mut rand::rngs::OsRng)
+    }
+
     /// Creates a new random keypair seeded with the provided RNG
+    ///
+    /// **Note:** a deterministically seeded RNG yields predictable keys, which is useful for
+    /// tests but NOT safe for production use. Prefer [`Wallet::new_random`] otherwise.
     pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
         let signer = SigningKey::random(rng);
         let address = secret_key_to_address(&signer);
@@ -130,7 +145,7 @@ impl FromStr for Wallet<SigningKey> {
     type Err = WalletError;
 
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        let src = hex::decode(src)?;
+        let src = SecretBytes::from(hex::decode(src)?);
         let sk = SigningKey::from_bytes(&src)?;
         Ok(sk.into())
     }
@@ -165,6 +180,42 @@ mod tests {
         std::fs::remove_file(&path).unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "zeroize")]
+    #[allow(unsafe_code)]
+    fn zeroizes_key_on_drop() {
+        use std::mem::{size_of, ManuallyDrop};
+
+        fn raw_bytes(wallet: &ManuallyDrop<Wallet<SigningKey>>) -> Vec<u8> {
+            let ptr = &**wallet as *const Wallet<SigningKey> as *const u8;
+            unsafe { std::slice::from_raw_parts(ptr, size_of::<Wallet<SigningKey>>()) }.to_vec()
+        }
+        fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+            haystack.windows(needle.len()).any(|window| window == needle)
+        }
+
+        let mut wallet = ManuallyDrop::new(Wallet::<SigningKey>::new_random());
+        // the secret scalar is held as little endian limbs
+        let mut secret = wallet.signer.to_bytes().to_vec();
+        secret.reverse();
+        assert!(contains(&raw_bytes(&wallet), &secret));
+
+        // SAFETY: the wallet is not used after being dropped, only its memory is inspected
+        unsafe { ManuallyDrop::drop(&mut wallet) };
+        assert!(!contains(&raw_bytes(&wallet), &secret));
+    }
+
+    #[tokio::test]
+    async fn new_random_wallets() {
+        let key = Wallet::<SigningKey>::new_random();
+        let other = Wallet::<SigningKey>::new_random();
+        assert_ne!(key.signer().to_bytes(), other.signer().to_bytes());
+        assert_ne!(key.address(), other.address());
+
+        let signature = key.sign_message("Some data").await.unwrap();
+        assert_eq!(signature.recover("Some data").unwrap(), key.address());
+    }
+
     #[tokio::test]
     async fn signs_msg() {
         let message = "Some data";
@@ -248,6 +299,49 @@ mod tests {
         sig.verify(sighash, wallet.address).unwrap();
     }
 
+    #[test]
+    #[cfg(not(feature = "celo"))]
+    fn signs_tx_eip155_and_legacy() {
+        use crate::TypedTransaction;
+        use ethers_core::{
+            types::{TransactionRequest, H256, U256},
+            utils::keccak256,
+        };
+        // retrieved test vector from:
+        // https://web3js.readthedocs.io/en/v1.2.0/web3-eth-accounts.html#eth-accounts-signtransaction
+        let tx = TransactionRequest {
+            from: None,
+            to: Some("F0109fC8DF283027b6285cc889F5aA624EaC1F55".parse::<Address>().unwrap().into()),
+            value: Some(1_000_000_000u64.into()),
+            gas: Some(2_000_000u64.into()),
+            nonce: Some(0u64.into()),
+            gas_price: Some(21_000_000_000u128.into()),
+            data: None,
+            chain_id: None,
+        };
+        let wallet: Wallet<SigningKey> =
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".parse().unwrap();
+
+        let sig = wallet.sign_transaction_sync(&TypedTransaction::Legacy(tx.clone().chain_id(1)));
+        assert_eq!(sig.v, 37);
+        assert_eq!(
+            sig.r,
+            U256::from_str("c9cf86333bcb065d140032ecaab5d9281bde80f21b9687b3e94161de42d51895")
+                .unwrap()
+        );
+        assert_eq!(
+            sig.s,
+            U256::from_str("727a108a0b8d101465414033c3f705a9c7b826e596766046ee1183dbc8aeaa68")
+                .unwrap()
+        );
+
+        #[allow(deprecated)]
+        let legacy = wallet.legacy_sign(&tx);
+        assert!(legacy.v == 27 || legacy.v == 28);
+        let sighash = H256::from(keccak256(tx.rlp_unsigned().as_ref()));
+        legacy.verify(sighash, wallet.address).unwrap();
+    }
+
     #[test]
     #[cfg(not(feature = "celo"))]
     fn signs_tx_empty_chain_id_sync() {